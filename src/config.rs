@@ -0,0 +1,418 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::model::Project;
+use chrono::Datelike;   // allows .year() on NaiveDate
+use std::path::PathBuf;
+
+pub const DEFAULT_INPUT: &str = "dpwh_flood_control_projects.csv";
+
+/* Settings for one pipeline run, passed from the controller into every report */
+#[derive(Debug, Clone)]
+pub struct RunConfig
+{
+    pub inputs: Vec<PathBuf>,   // files or glob patterns to load, merged into one dataset
+    pub sheet: Option<String>,  // workbook sheet, by name or 1-based position (first sheet if None)
+    pub header_row: usize,      // 1-based row holding the column names
+    pub contractor_aliases: Option<PathBuf>,    // reviewed contractor alias table that overrides automatic matching
+    pub output_dir: PathBuf,    // directory that receives every generated file
+    pub window: AnalysisWindow, // which projects are kept for analysis
+    pub allocation: AllocationPolicy,   // how clustered/MYCA amounts are shared within their group
+    pub jv_attribution: JvAttribution,  // how report2 credits joint ventures
+    pub delay_bounds: DelayBounds,      // plausibility check on the delay the reports use
+    pub delay_basis: DelayBasis,        // what "delay" means in the reports
+    pub use_snapshot: bool,             // reuse/write the binary snapshot of the cleaned dataset
+    pub formats: Vec<OutputFormat>,     // how report results are rendered
+}
+
+impl RunConfig
+{
+    // Constructor matching the original behaviour (dataset and outputs in the CWD)
+    pub fn new() -> Self
+    {
+        Self
+        {
+            inputs: vec![PathBuf::from(DEFAULT_INPUT)],
+            sheet: None,
+            header_row: 1,
+            contractor_aliases: None,
+            output_dir: PathBuf::from("."),
+            window: AnalysisWindow::new(),
+            allocation: AllocationPolicy::Inherit,
+            jv_attribution: JvAttribution::Entity,
+            delay_bounds: DelayBounds::new(),
+            delay_basis: DelayBasis::Duration,
+            use_snapshot: true,
+            formats: vec![OutputFormat::Table, OutputFormat::Csv],
+        }
+    }
+
+    /* The inputs as given (patterns not expanded), for messages */
+    pub fn describe_inputs(&self) -> String
+    {
+        self.inputs.iter().map(|p| p.display().to_string()).collect::<Vec<String>>().join(", ")
+    }
+
+    /* Full path of an output file inside the output directory */
+    pub fn output_path(&self, file_name: &str) -> PathBuf
+    {
+        self.output_dir.join(file_name)
+    }
+
+    // Settings line printed under every report title
+    pub fn header(&self) -> String
+    {
+        self.header_with(&[])
+    }
+
+    /* header() followed by settings that only some reports use, such as report2's joint-venture attribution */
+    pub fn header_with(&self, extra: &[String]) -> String
+    {
+        let mut settings = vec![
+            format!("Filtered: {}", self.window.describe()),
+            format!("clustered/MYCA amounts: {}", self.allocation.name()),
+            format!("delay = {}", self.delay_basis.name()),
+            self.delay_bounds.describe(self.delay_basis),
+        ];
+        settings.extend(extra.iter().cloned());
+        format!("({})", settings.join("; "))
+    }
+}
+
+impl Default for RunConfig
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/* Column whose year decides whether a project falls inside the analysis window */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearKey
+{
+    StartDate,
+    FundingYear,
+}
+
+impl YearKey
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            YearKey::StartDate => "StartDate",
+            YearKey::FundingYear => "FundingYear",
+        }
+    }
+
+    /* Accepts the column name in any case, with or without separators (e.g. "start-date") */
+    pub fn from_name(name: &str) -> Option<YearKey>
+    {
+        let key: String = name.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+        match key.as_str() {
+            "startdate" => Some(YearKey::StartDate),
+            "fundingyear" => Some(YearKey::FundingYear),
+            _ => None,
+        }
+    }
+}
+
+/* Inclusive year range applied once in the controller (REQ-0003) */
+#[derive(Debug, Clone, Copy)]
+pub struct AnalysisWindow
+{
+    pub start_year: i32,
+    pub end_year: i32,
+    pub key: YearKey,
+}
+
+impl AnalysisWindow
+{
+    // Constructor for the specification default: 2021–2023 by StartDate
+    pub fn new() -> Self
+    {
+        Self
+        {
+            start_year: 2021,
+            end_year: 2023,
+            key: YearKey::StartDate,
+        }
+    }
+
+    /* Year of the project according to the window's key, if the field is present */
+    pub fn year_of(&self, p: &Project) -> Option<i32>
+    {
+        match self.key {
+            YearKey::StartDate => p.start_date.map(|d| d.year()),
+            YearKey::FundingYear => p.funding_year,
+        }
+    }
+
+    pub fn contains(&self, p: &Project) -> bool
+    {
+        self.year_of(p).is_some_and(|y| (self.start_year..=self.end_year).contains(&y))
+    }
+
+    /* Parses "2021-2023" (or a single year "2022") into the window's bounds */
+    pub fn parse_years(&mut self, spec: &str) -> Result<(), String>
+    {
+        let (from, to) = spec.split_once(['-', '–']).unwrap_or((spec, spec));
+        let from: i32 = from.trim().parse().map_err(|_| format!("invalid year range '{spec}'"))?;
+        let to: i32 = to.trim().parse().map_err(|_| format!("invalid year range '{spec}'"))?;
+        if from > to {
+            return Err(format!("invalid year range '{spec}': start is after end"));
+        }
+        self.start_year = from;
+        self.end_year = to;
+        Ok(())
+    }
+
+    // e.g. "2021–2023 by StartDate", used in every report header
+    pub fn describe(&self) -> String
+    {
+        let years = if self.start_year == self.end_year {
+            self.start_year.to_string()
+        } else {
+            format!("{}–{}", self.start_year, self.end_year)
+        };
+        format!("{} by {}", years, self.key.name())
+    }
+}
+
+impl Default for AnalysisWindow
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/* How the amount of a contract is shared with the rows that reference it ("Clustered with Contract ID X", "MYCA with Project ID Y") */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationPolicy
+{
+    Inherit,    // every member carries the full amount (original behaviour, counts the money once per member)
+    ParentOnly, // only the row that holds the amount keeps it
+    Even,       // amount split evenly across the group
+    ProRata,    // split by each row's amount in the other money column, evenly if a row of the group has none of its own
+}
+
+impl AllocationPolicy
+{
+    pub const ALL: [AllocationPolicy; 4] = [AllocationPolicy::Inherit, AllocationPolicy::ParentOnly, AllocationPolicy::Even, AllocationPolicy::ProRata];
+
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            AllocationPolicy::Inherit => "inherit",
+            AllocationPolicy::ParentOnly => "parent-only",
+            AllocationPolicy::Even => "even",
+            AllocationPolicy::ProRata => "pro-rata",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<AllocationPolicy>
+    {
+        AllocationPolicy::ALL.into_iter().find(|p| p.name() == name.trim().to_lowercase())
+    }
+}
+
+/* How a joint venture's projects are credited in the contractor ranking */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JvAttribution
+{
+    Entity, // the joint venture is ranked as a contractor of its own
+    Full,   // every member firm is credited with the whole project
+    Share,  // every member firm is credited with an equal share of the project's amounts
+}
+
+impl JvAttribution
+{
+    pub const ALL: [JvAttribution; 3] = [JvAttribution::Entity, JvAttribution::Full, JvAttribution::Share];
+
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            JvAttribution::Entity => "entity",
+            JvAttribution::Full => "full",
+            JvAttribution::Share => "share",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<JvAttribution>
+    {
+        JvAttribution::ALL.into_iter().find(|a| a.name() == name.trim().to_lowercase())
+    }
+}
+
+/* One way of rendering a report result */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat
+{
+    Table,  // aligned table printed to the terminal
+    Csv,    // <report>.csv in the output directory
+    Json,   // <report>.json in the output directory (title, settings and rows)
+}
+
+impl OutputFormat
+{
+    pub const ALL: [OutputFormat; 3] = [OutputFormat::Table, OutputFormat::Csv, OutputFormat::Json];
+
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Json => "json",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<OutputFormat>
+    {
+        OutputFormat::ALL.into_iter().find(|f| f.name() == name.trim().to_lowercase())
+    }
+}
+
+/* Which derived field the reports use as a project's delay */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelayBasis
+{
+    Duration,   // ActualCompletionDate - StartDate (REQ-0004 CompletionDelayDays)
+    Slippage,   // ActualCompletionDate - TargetCompletionDate (schedule slippage)
+}
+
+impl DelayBasis
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            DelayBasis::Duration => "duration",
+            DelayBasis::Slippage => "slippage",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<DelayBasis>
+    {
+        [DelayBasis::Duration, DelayBasis::Slippage].into_iter().find(|b| b.name() == name.trim().to_lowercase())
+    }
+
+    /* Derived field that holds the delay */
+    pub fn field(&self) -> &'static str
+    {
+        match self {
+            DelayBasis::Duration => "CompletionDelayDays",
+            DelayBasis::Slippage => "ScheduleSlippageDays",
+        }
+    }
+}
+
+/* What happens to a delay outside the plausibility bounds */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelayPolicy
+{
+    Keep,       // flag the project but use the value as is
+    Exclude,    // drop the value so it does not enter any average
+    Cap,        // clamp the value to the nearest bound
+}
+
+impl DelayPolicy
+{
+    pub const ALL: [DelayPolicy; 3] = [DelayPolicy::Keep, DelayPolicy::Exclude, DelayPolicy::Cap];
+
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            DelayPolicy::Keep => "keep",
+            DelayPolicy::Exclude => "exclude",
+            DelayPolicy::Cap => "cap",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<DelayPolicy>
+    {
+        DelayPolicy::ALL.into_iter().find(|p| p.name() == name.trim().to_lowercase())
+    }
+}
+
+/* Plausible range for the delay the reports use (see DelayBasis), catching completion-before-start and typo years */
+#[derive(Debug, Clone, Copy)]
+pub struct DelayBounds
+{
+    pub min_days: Option<i64>,  // None: 0 for durations, -max_days for slippage (a project may finish before its target)
+    pub max_days: i64,
+    pub policy: DelayPolicy,
+}
+
+impl DelayBounds
+{
+    // Constructor for the defaults: up to 3650 days (ten years), implausible values excluded
+    pub fn new() -> Self
+    {
+        Self
+        {
+            min_days: None,
+            max_days: 3650,
+            policy: DelayPolicy::Exclude,
+        }
+    }
+
+    /* The (min, max) range applied to delays of the given basis */
+    pub fn range(&self, basis: DelayBasis) -> (i64, i64)
+    {
+        let min = self.min_days.unwrap_or(match basis {
+            DelayBasis::Duration => 0,
+            DelayBasis::Slippage => -self.max_days,
+        });
+        (min, self.max_days)
+    }
+
+    /* Parses "0:3650" into the bounds */
+    pub fn parse_range(&mut self, spec: &str) -> Result<(), String>
+    {
+        let invalid = || format!("invalid delay bounds '{spec}', expected MIN:MAX");
+        let (min, max) = spec.split_once(':').ok_or_else(invalid)?;
+        let min: i64 = min.trim().parse().map_err(|_| invalid())?;
+        let max: i64 = max.trim().parse().map_err(|_| invalid())?;
+        if min > max {
+            return Err(invalid());
+        }
+        self.min_days = Some(min);
+        self.max_days = max;
+        Ok(())
+    }
+
+    // e.g. "durations outside 0..3650 days: exclude", or "slippages outside ..." for the slippage basis
+    pub fn describe(&self, basis: DelayBasis) -> String
+    {
+        let (min, max) = self.range(basis);
+        let values = match basis {
+            DelayBasis::Duration => "durations",
+            DelayBasis::Slippage => "slippages",
+        };
+        format!("{values} outside {min}..{max} days: {}", self.policy.name())
+    }
+}
+
+impl Default for DelayBounds
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn defaults_keep_the_original_amounts()
+    {
+        // every clustered/MYCA row carries the full amount unless --allocation says otherwise
+        assert_eq!(RunConfig::new().allocation, AllocationPolicy::Inherit);
+    }
+}
@@ -0,0 +1,166 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::AllocationPolicy;
+use crate::controller::resolve::ColumnResolver;
+use crate::model::Resolution;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/* Amount assigned to one row of a money column (None for a ParentOnly member), and how the row's own cell resolved */
+#[derive(Debug, Clone)]
+pub struct Allocated
+{
+    pub amount: Option<f64>,
+    pub resolution: Resolution,
+}
+
+/* Number of clustered/MYCA groups found in a money column */
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct AllocationStats
+{
+    pub groups: usize,
+    pub members: usize,     // referencing rows, parents excluded
+    pub even_fallback: usize,   // ProRata shares split evenly instead, because a row of their group had no weight
+}
+
+/* Resolves every row of `column` and shares each group's amount according to `policy`.
+   `weights` is the other money column, used by ProRata (budget is split by cost and vice versa, see counterpart_weight). */
+pub fn allocate(column: &ColumnResolver, weights: &ColumnResolver, policy: AllocationPolicy) -> (Vec<Allocated>, AllocationStats)
+{
+    // 1. Follow every reference and group rows by the row that holds the amount
+    let mut rows: Vec<Allocated> = Vec::with_capacity(column.len());
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();   // parent row -> referencing rows
+
+    for row in 0..column.len() {
+        let (root, resolution) = column.follow(row);
+        if let (Some(parent), Resolution::Referenced(_)) = (root, &resolution) {
+            groups.entry(parent).or_default().push(row);
+        }
+        rows.push(Allocated { amount: root.and_then(|r| column.amount_at(r)), resolution });
+    }
+
+    let mut stats = AllocationStats
+    {
+        groups: groups.len(),
+        members: groups.values().map(|m| m.len()).sum(),
+        even_fallback: 0,
+    };
+
+    // 2. Share each group's amount between the parent and its members. Under ParentOnly a member has no amount
+    //    (None, not 0.0), so per-project statistics such as median savings leave it out instead of counting a zero.
+    for (parent, members) in groups {
+        let total = column.amount_at(parent).unwrap_or(0.0);
+        let mut group = members;
+        group.push(parent);
+
+        let even = Some(total / group.len() as f64);
+        let shares: Vec<Option<f64>> = match policy {
+            AllocationPolicy::Inherit => vec![Some(total); group.len()],
+            AllocationPolicy::ParentOnly => group.iter().map(|&r| (r == parent).then_some(total)).collect(),
+            AllocationPolicy::Even => vec![even; group.len()],
+            AllocationPolicy::ProRata => {
+                let w: Vec<Option<f64>> = group.iter().map(|&r| counterpart_weight(weights, r, &group)).collect();
+                let sum: f64 = w.iter().flatten().sum();
+                if w.iter().all(|v| v.is_some()) && sum > 0.0 {
+                    w.iter().map(|v| Some(total * v.unwrap_or(0.0) / sum)).collect()
+                } else {
+                    stats.even_fallback += group.len();
+                    vec![even; group.len()]    // no basis for pro-rata, fall back to even
+                }
+            }
+        };
+
+        for (&row, share) in group.iter().zip(shares) {
+            rows[row].amount = share;
+        }
+    }
+
+    (rows, stats)
+}
+
+/* A row's ProRata weight: its amount in the other money column, written in the row or reached through a reference.
+   A reference back into the row's own group gives no weight, since it would only repeat the parent's amount. */
+fn counterpart_weight(weights: &ColumnResolver, row: usize, group: &[usize]) -> Option<f64>
+{
+    let (root, _) = weights.follow(row);
+    root.filter(|&r| r == row || !group.contains(&r))
+        .and_then(|r| weights.amount_at(r))
+        .filter(|v| *v > 0.0)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::controller::resolve::RefParser;
+
+    // Budget and cost columns of a group: C1 holds the amounts, C2 and C3 reference it
+    fn columns(member_costs: [&str; 2]) -> (ColumnResolver, ColumnResolver)
+    {
+        let parser = RefParser::new();
+        let (mut budget, mut cost) = (ColumnResolver::new(), ColumnResolver::new());
+        budget.push("C1", "P1", parser.classify("300"));
+        cost.push("C1", "P1", parser.classify("120"));
+        for (i, member_cost) in member_costs.iter().enumerate() {
+            let contract = format!("C{}", i + 2);
+            budget.push(&contract, "", parser.classify("Clustered with Contract ID C1"));
+            cost.push(&contract, "", parser.classify(member_cost));
+        }
+        (budget, cost)
+    }
+
+    fn shares(policy: AllocationPolicy, member_costs: [&str; 2]) -> (Vec<Option<f64>>, AllocationStats)
+    {
+        let (budget, cost) = columns(member_costs);
+        let (rows, stats) = allocate(&budget, &cost, policy);
+        (rows.into_iter().map(|r| r.amount).collect(), stats)
+    }
+
+    const REFERENCED: [&str; 2] = ["Clustered with Contract ID C1", "Clustered with Contract ID C1"];
+
+    #[test]
+    fn inherit_repeats_the_amount()
+    {
+        let (amounts, stats) = shares(AllocationPolicy::Inherit, REFERENCED);
+        assert_eq!(amounts, [Some(300.0); 3]);
+        assert_eq!((stats.groups, stats.members), (1, 2));
+    }
+
+    #[test]
+    fn parent_only_leaves_members_without_an_amount()
+    {
+        let (amounts, _) = shares(AllocationPolicy::ParentOnly, REFERENCED);
+        assert_eq!(amounts, [Some(300.0), None, None]);
+    }
+
+    #[test]
+    fn even_splits_the_amount()
+    {
+        let (amounts, _) = shares(AllocationPolicy::Even, REFERENCED);
+        assert_eq!(amounts, [Some(100.0); 3]);
+    }
+
+    #[test]
+    fn pro_rata_uses_each_rows_own_counterpart()
+    {
+        let (amounts, stats) = shares(AllocationPolicy::ProRata, ["60", "120"]);
+        assert_eq!(amounts, [Some(120.0), Some(60.0), Some(120.0)]);
+        assert_eq!(stats.even_fallback, 0);
+    }
+
+    #[test]
+    fn pro_rata_without_weights_falls_back_to_even()
+    {
+        // the members' costs only point back at the parent, so they carry no weight of their own
+        let (amounts, stats) = shares(AllocationPolicy::ProRata, REFERENCED);
+        assert_eq!(amounts, [Some(100.0); 3]);
+        assert_eq!(stats.even_fallback, 3);
+
+        let (_, stats) = shares(AllocationPolicy::ProRata, ["60", ""]);
+        assert_eq!(stats.even_fallback, 3);
+    }
+}
@@ -0,0 +1,151 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use flate2::bufread::MultiGzDecoder;    // .gz, including files of several concatenated gzip members
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use zip::ZipArchive;
+
+/* How an input file is compressed, decided by its first bytes rather than its extension */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression
+{
+    Plain,
+    Gzip,
+    Zstd,
+    Zip,
+}
+
+impl Compression
+{
+    /* Matches the magic bytes at the start of the file; anything else is read as plain text */
+    pub fn detect(head: &[u8]) -> Self
+    {
+        if head.starts_with(&[0x1f, 0x8b]) {
+            Compression::Gzip
+        } else if head.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Compression::Zstd
+        } else if head.starts_with(b"PK\x03\x04") {
+            Compression::Zip
+        } else {
+            Compression::Plain
+        }
+    }
+}
+
+/* An open input file; `reader` streams its decompressed content without extracting anything to disk */
+pub enum Source
+{
+    Plain(BufReader<File>),
+    Gzip(BufReader<File>),
+    Zstd(BufReader<File>),
+    Zip(ZipArchive<BufReader<File>>, usize),   // archive and the index of its only file
+}
+
+impl Source
+{
+    pub fn open(path: &Path) -> Result<Self, Box<dyn Error>>
+    {
+        let mut file = BufReader::new(File::open(path).map_err(|e| format!("{}: {e}", path.display()))?);
+        let compression = Compression::detect(file.fill_buf()?);  // peeks without consuming
+        Ok(match compression {
+            Compression::Plain => Source::Plain(file),
+            Compression::Gzip => Source::Gzip(file),
+            Compression::Zstd => Source::Zstd(file),
+            Compression::Zip => {
+                let archive = ZipArchive::new(file)?;
+                let entries: Vec<usize> = (0..archive.len())
+                    .filter(|&i| archive.name_for_index(i).is_some_and(|name| !name.ends_with('/')))  // directories are not data
+                    .collect();
+                match entries[..] {
+                    [only] => Source::Zip(archive, only),
+                    _ => return Err(format!("{} must hold exactly one file, found {}", path.display(), entries.len()).into()),
+                }
+            }
+        })
+    }
+
+    /* Decompressed content; borrows the source, so it is read while the source is alive */
+    pub fn reader(&mut self) -> Result<Box<dyn Read + '_>, Box<dyn Error>>
+    {
+        Ok(match self {
+            Source::Plain(file) => Box::new(file),
+            Source::Gzip(file) => Box::new(MultiGzDecoder::new(file)),
+            Source::Zstd(file) => Box::new(zstd::stream::read::Decoder::with_buffer(file)?),
+            Source::Zip(archive, index) => Box::new(archive.by_index(*index)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    const CSV: &str = "ProjectId,ContractCost\nP1,100\n";
+
+    // Writes `bytes` to a file of the system temp directory, unique to this test run
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf
+    {
+        let path = std::env::temp_dir().join(format!("decompress-{}-{name}", std::process::id()));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    fn read_back(path: &Path) -> String
+    {
+        let mut text = String::new();
+        Source::open(path).unwrap().reader().unwrap().read_to_string(&mut text).unwrap();
+        std::fs::remove_file(path).unwrap();
+        text
+    }
+
+    #[test]
+    fn compression_is_detected_by_magic_bytes()
+    {
+        assert_eq!(Compression::detect(&[0x1f, 0x8b, 0x08]), Compression::Gzip);
+        assert_eq!(Compression::detect(&[0x28, 0xb5, 0x2f, 0xfd, 0x00]), Compression::Zstd);
+        assert_eq!(Compression::detect(b"PK\x03\x04rest"), Compression::Zip);
+        assert_eq!(Compression::detect(CSV.as_bytes()), Compression::Plain);
+        assert_eq!(Compression::detect(&[]), Compression::Plain);
+    }
+
+    #[test]
+    fn every_format_reads_back_the_same_text()
+    {
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(CSV.as_bytes()).unwrap();
+        let zstd = zstd::encode_all(CSV.as_bytes(), 0).unwrap();
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        zip.add_directory("data/", zip::write::SimpleFileOptions::default()).unwrap();    // directories are skipped
+        zip.start_file("data/projects.csv", zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(CSV.as_bytes()).unwrap();
+
+        // the extension says nothing: every file is named .csv
+        assert_eq!(read_back(&temp_file("plain.csv", CSV.as_bytes())), CSV);
+        assert_eq!(read_back(&temp_file("gzip.csv", &gzip.finish().unwrap())), CSV);
+        assert_eq!(read_back(&temp_file("zstd.csv", &zstd)), CSV);
+        assert_eq!(read_back(&temp_file("zip.csv", &zip.finish().unwrap().into_inner())), CSV);
+    }
+
+    #[test]
+    fn a_zip_archive_must_hold_one_file()
+    {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for name in ["a.csv", "b.csv"] {
+            zip.start_file(name, zip::write::SimpleFileOptions::default()).unwrap();
+            zip.write_all(CSV.as_bytes()).unwrap();
+        }
+        let path = temp_file("two.zip", &zip.finish().unwrap().into_inner());
+        let error = Source::open(&path).err().map(|e| e.to_string());
+        std::fs::remove_file(&path).unwrap();
+        assert!(error.is_some_and(|e| e.ends_with("must hold exactly one file, found 2")));
+    }
+}
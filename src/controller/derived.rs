@@ -0,0 +1,110 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::{DelayBasis, DelayBounds, DelayPolicy};
+use crate::model::{DelayIssue, Project};
+
+/* Checks the delay the reports use (completion_delay_days or schedule_slippage_days, per `basis`) against the bounds,
+   flags the project and applies the policy. An implausible duration means its start or completion date is wrong,
+   so Exclude also drops the schedule slippage; an implausible slippage may only be a wrong target date.
+   Returns the issue found, if any. */
+pub fn check_delay(p: &mut Project, bounds: &DelayBounds, basis: DelayBasis) -> Option<DelayIssue>
+{
+    let days = p.delay_days(basis)?;
+    let (min, max) = bounds.range(basis);
+    let issue = if days < min {
+        DelayIssue::BelowMinimum { days }
+    } else if days > max {
+        DelayIssue::AboveMaximum { days }
+    } else {
+        return None;
+    };
+
+    p.delay_issue = Some(issue);
+    let kept = match bounds.policy {
+        DelayPolicy::Keep => Some(days),
+        DelayPolicy::Exclude => None,
+        DelayPolicy::Cap => Some(days.clamp(min, max)),
+    };
+    match basis {
+        DelayBasis::Duration => {
+            p.completion_delay_days = kept;
+            if kept.is_none() {
+                p.schedule_slippage_days = None;
+            }
+        }
+        DelayBasis::Slippage => p.schedule_slippage_days = kept,
+    }
+    Some(issue)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn project(duration: i64, slippage: i64) -> Project
+    {
+        let mut p = Project::new();
+        p.completion_delay_days = Some(duration);
+        p.schedule_slippage_days = Some(slippage);
+        p
+    }
+
+    fn bounds(policy: DelayPolicy) -> DelayBounds
+    {
+        DelayBounds { policy, ..DelayBounds::new() }
+    }
+
+    #[test]
+    fn exclude_drops_an_implausible_duration_and_its_slippage()
+    {
+        let mut p = project(5000, 40);
+        assert_eq!(check_delay(&mut p, &bounds(DelayPolicy::Exclude), DelayBasis::Duration), Some(DelayIssue::AboveMaximum { days: 5000 }));
+        assert_eq!((p.completion_delay_days, p.schedule_slippage_days), (None, None));
+        assert_eq!(p.delay_issue, Some(DelayIssue::AboveMaximum { days: 5000 }));
+    }
+
+    #[test]
+    fn cap_clamps_to_the_nearest_bound()
+    {
+        let mut late = project(5000, 40);
+        check_delay(&mut late, &bounds(DelayPolicy::Cap), DelayBasis::Duration);
+        assert_eq!((late.completion_delay_days, late.schedule_slippage_days), (Some(3650), Some(40)));
+
+        let mut early = project(-12, 40);
+        assert_eq!(check_delay(&mut early, &bounds(DelayPolicy::Cap), DelayBasis::Duration), Some(DelayIssue::BelowMinimum { days: -12 }));
+        assert_eq!(early.completion_delay_days, Some(0));
+    }
+
+    #[test]
+    fn keep_flags_the_value_but_leaves_it()
+    {
+        let mut p = project(-12, 40);
+        assert_eq!(check_delay(&mut p, &bounds(DelayPolicy::Keep), DelayBasis::Duration), Some(DelayIssue::BelowMinimum { days: -12 }));
+        assert_eq!((p.completion_delay_days, p.schedule_slippage_days), (Some(-12), Some(40)));
+        assert!(p.delay_issue.is_some());
+
+        let mut plausible = project(200, 40);
+        assert_eq!(check_delay(&mut plausible, &bounds(DelayPolicy::Keep), DelayBasis::Duration), None);
+        assert_eq!(plausible.delay_issue, None);
+    }
+
+    #[test]
+    fn the_slippage_basis_checks_the_slippage()
+    {
+        let bounds = DelayBounds::new();
+        let mut p = project(100, 5000);
+        assert_eq!(check_delay(&mut p, &bounds, DelayBasis::Slippage), Some(DelayIssue::AboveMaximum { days: 5000 }));
+        assert_eq!((p.completion_delay_days, p.schedule_slippage_days), (Some(100), None));    // the duration stays
+
+        // finishing before the target is plausible; the same value as a duration is not
+        let mut early = project(-30, -30);
+        assert_eq!(check_delay(&mut early, &bounds, DelayBasis::Slippage), None);
+        assert_eq!(check_delay(&mut early, &bounds, DelayBasis::Duration), Some(DelayIssue::BelowMinimum { days: -30 }));
+        assert_eq!((early.completion_delay_days, early.schedule_slippage_days), (None, None));
+    }
+}
@@ -0,0 +1,505 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::model::Project;
+use regex::Regex;   // for "(FORMERLY: ...)" aliases
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::path::Path;
+use std::sync::LazyLock;
+
+// Shortest word a one-letter typo is tolerated in; in shorter words ("RCG"/"RCB", "MARK"/"MARC") one letter is another firm
+const FUZZY_MIN_LEN: usize = 6;
+
+// Words many firms share; a typo in one of them ("CONSTRUCTON") says nothing about which firm it is
+const GENERIC_WORDS: [&str; 14] = [
+    "CONSTRUCTION", "BUILDERS", "BUILDER", "DEVELOPMENT", "ENGINEERING", "ENTERPRISES", "GENERAL",
+    "SERVICES", "TRADING", "CONTRACTOR", "CONTRACTORS", "SUPPLY", "JOINT", "VENTURE",
+];
+
+// Legal-form words dropped from the end of a name key ("SUNWEST, INC." and "Sunwest Inc" are the same firm)
+const LEGAL_SUFFIXES: [&str; 10] = ["INC", "INCORPORATED", "CORP", "CORPORATION", "CO", "COMPANY", "LTD", "LIMITED", "LLC", "OPC"];
+
+// Abbreviations spelled out in name keys
+const ABBREVIATIONS: [(&str, &str); 12] = [
+    ("CONST", "CONSTRUCTION"),
+    ("CONSTN", "CONSTRUCTION"),
+    ("CONSTR", "CONSTRUCTION"),
+    ("BLDRS", "BUILDERS"),
+    ("DEV", "DEVELOPMENT"),
+    ("DEVT", "DEVELOPMENT"),
+    ("ENGG", "ENGINEERING"),
+    ("ENGR", "ENGINEERING"),
+    ("ENT", "ENTERPRISES"),
+    ("GEN", "GENERAL"),
+    ("SVCS", "SERVICES"),
+    ("TRDG", "TRADING"),
+];
+
+// "(FORMERLY: X)", "FORMERLY KNOWN AS X", "(F.K.A. X)"
+static FORMERLY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)[(\[]?\s*\b(?:formerly(?:\s+known\s+as)?|f\.?k\.?a)\b\.?\s*:?\s*([^)\]]*)[)\]]?").unwrap()
+});
+
+// "JOINT VENTURE", "JV", "J.V."
+static JV_MARKER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\b(?:joint\s+venture|j\.?\s?v)\b\.?").unwrap());
+
+// "&" and "AND" also occur inside one firm's name ("ROADS & BRIDGES"), so they only separate members in some cases
+static WEAK_SEPARATOR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\s*&\s*|\s+and\s+").unwrap());
+
+/* How a raw contractor name was tied to its entity */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchMethod
+{
+    Exact,      // the name is the canonical one
+    Normalized, // same name once case, punctuation, abbreviations and legal suffixes are ignored
+    Formerly,   // linked through a "(FORMERLY: ...)" alias
+    Reviewed,   // taken from a reviewed alias table
+}
+
+impl MatchMethod
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            MatchMethod::Exact => "exact",
+            MatchMethod::Normalized => "normalized",
+            MatchMethod::Formerly => "formerly",
+            MatchMethod::Reviewed => "reviewed",
+        }
+    }
+}
+
+/* One raw contractor string and the entity it was resolved to, for contractor_aliases.csv.
+   Near-duplicate spellings are never merged automatically: they are only suggested for review. */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AliasEntry
+{
+    pub raw: String,
+    pub canonical: String,
+    pub method: MatchMethod,
+    pub suggestion: Option<String>,     // larger entity this name may be a misspelling of (see fuzzy_similarity)
+    pub similarity: Option<f64>,        // of the two name keys, when there is a suggestion
+    pub projects: usize,
+    pub members: Vec<String>,   // canonical member names if the name is a joint venture
+}
+
+/* Decisions read from a reviewed alias table, which win over the automatic matching */
+#[derive(Debug, Clone, Default)]
+pub struct ReviewedAliases
+{
+    pub canonical: HashMap<String, String>,     // raw name -> canonical name
+    pub members: HashMap<String, Vec<String>>,  // raw name -> joint venture members (empty: a single firm)
+}
+
+/* A contractor cell that names a firm (not empty, not a cluster/MYCA reference) */
+pub fn is_contractor_name(raw: &str) -> bool
+{
+    let lower = raw.trim().to_lowercase();
+    !lower.is_empty() && !lower.contains("clustered with contract id") && !lower.contains("myca with project id")
+}
+
+/* Splits "NAME (FORMERLY: OLD NAME)" into the current name and its former names */
+pub fn split_formerly(raw: &str) -> (String, Vec<String>)
+{
+    let formers: Vec<String> = FORMERLY_RE.captures_iter(raw)
+        .map(|c| c[1].trim().to_string())
+        .filter(|f| !f.is_empty())
+        .collect();
+    let current = FORMERLY_RE.replace_all(raw, " ");
+    (collapse(&current), formers)
+}
+
+/* Member firms of a joint venture name, empty if the name is one firm.
+   "/" and "+" always separate members; "&" and "AND" only do when the name is marked as a joint venture
+   ("A & B JV") or every part ends in a legal suffix ("A CORP. & B INC."), so "SUNWEST CONST. & DEV. CORP." stays one firm. */
+pub fn split_joint_venture(name: &str) -> Vec<String>
+{
+    let marked = JV_MARKER_RE.is_match(name);
+    let unmarked = JV_MARKER_RE.replace_all(name, " ");
+    let parts = |pieces: Vec<&str>| -> Vec<String> {
+        pieces.into_iter()
+            .map(|m| collapse(m.trim_matches(|c: char| c.is_whitespace() || c == ',' || c == '-')))
+            .filter(|m| m.chars().any(char::is_alphanumeric))
+            .collect()
+    };
+
+    let strong = parts(unmarked.split(['/', '+']).collect());
+    if strong.len() > 1 {
+        return strong;
+    }
+    let weak = parts(WEAK_SEPARATOR_RE.split(&unmarked).collect());
+    if weak.len() > 1 && (marked || weak.iter().all(|m| has_legal_suffix(m))) {
+        return weak;
+    }
+    Vec::new()
+}
+
+// True if the last word of a name is a legal form ("CORP.", "INC")
+fn has_legal_suffix(name: &str) -> bool
+{
+    name.split_whitespace()
+        .next_back()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_uppercase())
+        .is_some_and(|w| LEGAL_SUFFIXES.contains(&w.as_str()))
+}
+
+// Trimmed, single-spaced, uppercased
+fn collapse(name: &str) -> String
+{
+    name.split_whitespace().collect::<Vec<&str>>().join(" ").to_uppercase()
+}
+
+/* Comparison key of a name: uppercase words only, abbreviations spelled out, legal suffixes dropped */
+pub fn name_key(name: &str) -> String
+{
+    let cleaned: String = name.to_uppercase().replace('&', " AND ").chars().map(|c| if c.is_alphanumeric() { c } else { ' ' }).collect();
+    let mut words: Vec<&str> = cleaned
+        .split_whitespace()
+        .map(|w| ABBREVIATIONS.iter().find(|(short, _)| *short == w).map_or(w, |(_, long)| *long))
+        .collect();
+    if words.len() > 1 && words[0] == "THE" {
+        words.remove(0);
+    }
+    while words.len() > 1 && words.last().is_some_and(|w| LEGAL_SUFFIXES.contains(w)) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+// A generic word, or a one-letter typo of one ("CONSTRUCTON"), spelled correctly
+fn generic_word(word: &str) -> Option<&'static str>
+{
+    GENERIC_WORDS.into_iter().find(|g| *g == word || (word.len() >= FUZZY_MIN_LEN && strsim::levenshtein(g, word) == 1))
+}
+
+/* Similarity of two different name keys that look like spellings of one firm, None if they do not.
+   The keys are compared word by word: they must have as many words, and differ in exactly one of them, by a
+   misspelled generic word or a one-letter typo in a word of at least FUZZY_MIN_LEN letters. Numbers must match
+   ("JV 1" is not "JV 2"). */
+pub fn fuzzy_similarity(a: &str, b: &str) -> Option<f64>
+{
+    let (wa, wb): (Vec<&str>, Vec<&str>) = (a.split(' ').collect(), b.split(' ').collect());
+    if a == b || wa.len() != wb.len() {
+        return None;
+    }
+    let differing: Vec<(&str, &str)> = wa.into_iter().zip(wb).filter(|(x, y)| x != y).collect();
+    let [(x, y)] = differing[..] else {
+        return None;
+    };
+    let digits = |s: &str| s.chars().filter(char::is_ascii_digit).collect::<String>();
+    let typo = x.len().min(y.len()) >= FUZZY_MIN_LEN && strsim::levenshtein(x, y) == 1;
+    let same_generic = generic_word(x).is_some_and(|g| generic_word(y) == Some(g));
+    (digits(x) == digits(y) && (typo || same_generic)).then(|| strsim::normalized_levenshtein(a, b))
+}
+
+/* Union-find over name keys (only FORMERLY links, which the data states explicitly, merge keys) */
+struct Clusters
+{
+    parent: Vec<usize>,
+}
+
+impl Clusters
+{
+    fn find(&mut self, mut i: usize) -> usize
+    {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize)
+    {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra != rb {
+            self.parent[ra.max(rb)] = ra.min(rb);
+        }
+    }
+}
+
+/* Resolves every contractor name of the dataset to one entity and stores it in Project::contractor_entity;
+   the members of a joint venture are resolved the same way and stored in Project::contractor_members.
+   `reviewed` holds the canonical names and joint venture members chosen by a reviewer.
+   Returns the alias table, sorted by canonical name. */
+pub fn resolve(projects: &mut [Project], reviewed: &ReviewedAliases) -> Vec<AliasEntry>
+{
+    // 1. Distinct raw names (BTreeMap keeps the result independent of row order)
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for p in projects.iter() {
+        if let Some(raw) = p.contractor.as_deref().map(str::trim).filter(|r| is_contractor_name(r)) {
+            *counts.entry(raw.to_string()).or_default() += 1;
+        }
+    }
+
+    // 2. Every key a raw name is known by: its current name and any former names; joint venture members get keys too
+    let mut keys: Vec<String> = Vec::new();
+    let mut key_index: HashMap<String, usize> = HashMap::new();
+    let mut index_of = |key: String, keys: &mut Vec<String>| -> usize {
+        *key_index.entry(key.clone()).or_insert_with(|| {
+            keys.push(key);
+            keys.len() - 1
+        })
+    };
+    let mut names: Vec<(String, String, usize)> = Vec::new();  // (raw, display name, key)
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    let mut members: Vec<Vec<(String, usize)>> = Vec::new();   // per name: (member name, key)
+    let mut joint_ventures: HashSet<usize> = HashSet::new();
+    for raw in counts.keys() {
+        let (current, formers) = split_formerly(raw);
+        let key = index_of(name_key(&current), &mut keys);
+        for former in &formers {
+            links.push((key, index_of(name_key(former), &mut keys)));
+        }
+        let found = reviewed.members.get(raw).cloned().unwrap_or_else(|| split_joint_venture(&current));
+        let split: Vec<(String, usize)> = found.into_iter().map(|m| {
+            let member_key = index_of(name_key(&m), &mut keys);
+            (m, member_key)
+        }).collect();
+        if !split.is_empty() || JV_MARKER_RE.is_match(&current) {
+            joint_ventures.insert(key);
+        }
+        members.push(split);
+        names.push((raw.clone(), current, key));
+    }
+
+    // 3. Merge keys linked by FORMERLY
+    let mut clusters = Clusters { parent: (0..keys.len()).collect() };
+    for &(a, b) in &links {
+        clusters.union(a, b);
+    }
+
+    // 4. The canonical name of a cluster: a current (not former) name, the key with the most projects,
+    //    then that key's most used spelling (ties: shorter, then alphabetical)
+    let superseded: HashSet<usize> = links.iter().filter(|(current, former)| current != former).map(|&(_, former)| former).collect();
+    let mut key_projects: HashMap<usize, usize> = HashMap::new();
+    for (raw, _, key) in &names {
+        *key_projects.entry(*key).or_default() += counts[raw];
+    }
+    let rank = |raw: &str, display: &str, key: usize| {
+        (!superseded.contains(&key), key_projects[&key], counts[raw], Reverse(display.len()), Reverse(display.to_string()))
+    };
+    let mut best: HashMap<usize, (&str, &str, usize)> = HashMap::new();    // root -> (raw, display name, key)
+    for (raw, display, key) in &names {
+        let root = clusters.find(*key);
+        if best.get(&root).is_none_or(|&(r, d, k)| rank(raw, display, *key) > rank(r, d, k)) {
+            best.insert(root, (raw.as_str(), display.as_str(), *key));
+        }
+    }
+
+    // 5. Fuzzy suggestions between keys of names that share their first two characters, always from the entity with
+    //    fewer projects to the one with more. Pairs are compared directly, so suggestions never chain (A~B, B~C).
+    //    A joint venture is never a near-duplicate of a single firm, e.g. one of its members.
+    let mut entity_projects: HashMap<usize, usize> = HashMap::new();
+    for (&key, &n) in &key_projects {
+        *entity_projects.entry(clusters.find(key)).or_default() += n;
+    }
+    let mut blocks: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for &key in key_projects.keys() {
+        blocks.entry(keys[key].chars().take(2).collect()).or_default().push(key);
+    }
+    let mut suggestions: HashMap<usize, (f64, usize)> = HashMap::new();  // key -> (similarity, root of the suggested entity)
+    for block in blocks.values() {
+        for &a in block {
+            for &b in block {
+                let (ra, rb) = (clusters.find(a), clusters.find(b));
+                let smaller = (entity_projects[&ra], best[&rb].1) < (entity_projects[&rb], best[&ra].1);
+                if ra == rb || !smaller || joint_ventures.contains(&a) != joint_ventures.contains(&b) {
+                    continue;
+                }
+                if let Some(similarity) = fuzzy_similarity(&keys[a], &keys[b])
+                    && suggestions.get(&a).is_none_or(|&(s, r)| (similarity, Reverse(best[&rb].1)) > (s, Reverse(best[&r].1)))
+                {
+                    suggestions.insert(a, (similarity, rb));
+                }
+            }
+        }
+    }
+
+    // 6. Alias table; a member takes the canonical name of the firm it matches, or its own spelling
+    let mut table: Vec<AliasEntry> = names.iter().zip(&members).map(|((raw, display, key), split)| {
+        let (_, canonical, canonical_key) = best[&clusters.find(*key)];
+        let method = if reviewed.canonical.contains_key(raw) {
+            MatchMethod::Reviewed
+        } else if display == canonical {
+            MatchMethod::Exact
+        } else if *key == canonical_key {
+            MatchMethod::Normalized
+        } else {
+            MatchMethod::Formerly
+        };
+        let suggestion = suggestions.get(key);
+        AliasEntry
+        {
+            raw: raw.clone(),
+            canonical: reviewed.canonical.get(raw).cloned().unwrap_or_else(|| canonical.to_string()),
+            method,
+            suggestion: suggestion.map(|&(_, root)| best[&root].1.to_string()),
+            similarity: suggestion.map(|&(similarity, _)| similarity),
+            projects: counts[raw],
+            members: split.iter().fold(Vec::new(), |mut resolved, (member, member_key)| {
+                let name = reviewed.canonical.get(member).cloned()
+                    .or_else(|| best.get(&clusters.find(*member_key)).map(|&(_, d, _)| d.to_string()))
+                    .unwrap_or_else(|| member.clone());
+                if !resolved.contains(&name) {
+                    resolved.push(name);
+                }
+                resolved
+            }),
+        }
+    }).collect();
+    table.sort_by(|a, b| a.canonical.cmp(&b.canonical).then(b.projects.cmp(&a.projects)).then(a.raw.cmp(&b.raw)));
+
+    // 7. Apply to every project
+    let lookup: HashMap<&str, &AliasEntry> = table.iter().map(|e| (e.raw.as_str(), e)).collect();
+    for p in projects.iter_mut() {
+        let entry = p.contractor.as_deref().and_then(|raw| lookup.get(raw.trim()));
+        p.contractor_entity = entry.map(|e| e.canonical.clone());
+        p.contractor_members = entry.map(|e| e.members.clone()).unwrap_or_default();
+    }
+    table
+}
+
+/* Reads a reviewed alias table (contractor_aliases.csv, possibly edited): RawName -> Canonical, and
+   RawName -> Members ("A; B", empty for a single firm) if the table has a Members column */
+pub fn read_reviewed(path: &Path) -> Result<ReviewedAliases, Box<dyn Error>>
+{
+    let mut rdr = csv::Reader::from_path(path)?;
+    let headers = rdr.headers()?.clone();
+    let position = |name: &str| headers.iter().position(|h| h.trim().eq_ignore_ascii_case(name));
+    let column = |name: &str| position(name).ok_or_else(|| format!("{} has no {name} column", path.display()));
+    let (raw, canonical, members) = (column("RawName")?, column("Canonical")?, position("Members"));
+
+    let mut reviewed = ReviewedAliases::default();
+    for record in rdr.records() {
+        let record = record?;
+        let (r, c) = (record.get(raw).unwrap_or("").trim(), record.get(canonical).unwrap_or("").trim());
+        if r.is_empty() {
+            continue;
+        }
+        if !c.is_empty() {
+            reviewed.canonical.insert(r.to_string(), c.to_string());
+        }
+        if let Some(m) = members.map(|i| record.get(i).unwrap_or("")) {
+            let split = m.split(';').map(str::trim).filter(|m| !m.is_empty()).map(str::to_string).collect();
+            reviewed.members.insert(r.to_string(), split);
+        }
+    }
+    Ok(reviewed)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    // One project per name, repeated `n` times
+    fn projects(names: &[(&str, usize)]) -> Vec<Project>
+    {
+        names.iter().flat_map(|&(name, n)| (0..n).map(move |_| {
+            let mut p = Project::new();
+            p.contractor = Some(name.to_string());
+            p
+        })).collect()
+    }
+
+    fn entry<'a>(table: &'a [AliasEntry], raw: &str) -> &'a AliasEntry
+    {
+        table.iter().find(|e| e.raw == raw).unwrap()
+    }
+
+    #[test]
+    fn legal_suffixes_and_abbreviations_are_ignored()
+    {
+        assert_eq!(name_key("SUNWEST, INC."), name_key("Sunwest Inc"));
+        assert_eq!(name_key("Legacy Const. Corp."), name_key("LEGACY CONSTRUCTION CORPORATION"));
+        assert_ne!(name_key("SUNWEST CONST. & DEV. CORP."), name_key("SUNWEST, INC."));
+        assert_ne!(name_key("X CONSTRUCTION PHILIPPINES"), name_key("X CONSTRUCTION"));    // not a legal form
+        assert_eq!(name_key("SMITH & CO., INC."), name_key("Smith and Co"));
+
+        let mut list = projects(&[("SUNWEST, INC.", 3), ("Sunwest Inc", 1)]);
+        let table = resolve(&mut list, &ReviewedAliases::default());
+        assert_eq!(entry(&table, "Sunwest Inc").canonical, "SUNWEST, INC.");
+        assert_eq!(entry(&table, "Sunwest Inc").method, MatchMethod::Normalized);
+        assert!(list.iter().all(|p| p.contractor_entity.as_deref() == Some("SUNWEST, INC.")));
+    }
+
+    #[test]
+    fn formerly_links_the_old_name_to_the_current_one()
+    {
+        assert_eq!(split_formerly("NEWCO BUILDERS (FORMERLY: GAMMA CORP.)"), ("NEWCO BUILDERS".to_string(), vec!["GAMMA CORP.".to_string()]));
+
+        let mut list = projects(&[("GAMMA CORP", 5), ("NEWCO BUILDERS (FORMERLY: GAMMA CORP.)", 2)]);
+        let table = resolve(&mut list, &ReviewedAliases::default());
+        assert_eq!(entry(&table, "GAMMA CORP").canonical, "NEWCO BUILDERS");
+        assert_eq!(entry(&table, "GAMMA CORP").method, MatchMethod::Formerly);
+    }
+
+    #[test]
+    fn near_duplicates_are_suggested_not_merged()
+    {
+        let jv = "ALPHA BUILDERS / BETA CONSTRUCTION JOINT VENTURE";
+        let typo = "ALPHA BUILDERS / BETA CONSTRUCTON JOINT VENTURE";
+        let mut list = projects(&[(jv, 5), (typo, 1), ("SUNWEST DEVELOPERS", 4), ("SUNWEST DEVELOPRS", 1)]);
+        let table = resolve(&mut list, &ReviewedAliases::default());
+
+        let misspelled = entry(&table, typo);
+        assert_eq!(misspelled.canonical, typo);
+        assert_eq!(misspelled.suggestion.as_deref(), Some(jv));
+        assert!(misspelled.similarity.is_some_and(|s| s > 0.9));
+        assert_eq!(entry(&table, jv).suggestion, None);   // only from the smaller entity to the larger
+        assert_eq!(entry(&table, "SUNWEST DEVELOPRS").suggestion.as_deref(), Some("SUNWEST DEVELOPERS"));
+    }
+
+    #[test]
+    fn near_misses_are_different_firms()
+    {
+        assert_eq!(fuzzy_similarity(&name_key("RCG CONSTRUCTION"), &name_key("RCB CONSTRUCTION")), None);
+        assert_eq!(fuzzy_similarity(&name_key("MARK CONSTRUCTION"), &name_key("MARC CONSTRUCTION")), None);
+        assert_eq!(fuzzy_similarity(&name_key("ALPHA BUILDERS"), &name_key("ALPHA CONSTRUCTION")), None);
+        assert_eq!(fuzzy_similarity(&name_key("ABC BUILDERS 1"), &name_key("ABC BUILDERS 2")), None);
+        assert_eq!(fuzzy_similarity(&name_key("SUNWEST BUILDERS"), &name_key("SUNWAST BILDERS")), None);  // two words differ
+
+        let mut list = projects(&[("RCG CONSTRUCTION", 5), ("RCB CONSTRUCTION", 5)]);
+        let table = resolve(&mut list, &ReviewedAliases::default());
+        assert!(table.iter().all(|e| e.suggestion.is_none() && e.canonical == e.raw));
+    }
+
+    #[test]
+    fn suggestions_do_not_chain()
+    {
+        // A~B and B~C, but A and C differ in two letters of one word
+        let mut list = projects(&[("DELACRUZ BUILDERS", 5), ("DELACRUS BUILDERS", 3), ("DELACRAS BUILDERS", 1)]);
+        let table = resolve(&mut list, &ReviewedAliases::default());
+        assert_eq!(entry(&table, "DELACRUS BUILDERS").suggestion.as_deref(), Some("DELACRUZ BUILDERS"));
+        assert_eq!(entry(&table, "DELACRAS BUILDERS").suggestion.as_deref(), Some("DELACRUS BUILDERS"));
+        assert_eq!(table.iter().map(|e| e.canonical.as_str()).collect::<HashSet<&str>>().len(), 3);
+    }
+
+    #[test]
+    fn reviewed_names_win()
+    {
+        let mut reviewed = ReviewedAliases::default();
+        reviewed.canonical.insert("RCB CONSTRUCTION".to_string(), "RCG CONSTRUCTION".to_string());
+        let mut list = projects(&[("RCG CONSTRUCTION", 5), ("RCB CONSTRUCTION", 5)]);
+        let table = resolve(&mut list, &reviewed);
+        assert_eq!(entry(&table, "RCB CONSTRUCTION").method, MatchMethod::Reviewed);
+        assert!(list.iter().all(|p| p.contractor_entity.as_deref() == Some("RCG CONSTRUCTION")));
+    }
+
+    #[test]
+    fn joint_ventures_are_split_into_members()
+    {
+        assert_eq!(split_joint_venture("ALPHA BUILDERS / BETA CONSTRUCTION JOINT VENTURE"), vec!["ALPHA BUILDERS", "BETA CONSTRUCTION"]);
+        assert_eq!(split_joint_venture("A1 CORP. & B2 INC."), vec!["A1 CORP.", "B2 INC."]);
+        assert_eq!(split_joint_venture("Gamma + Delta J.V."), vec!["GAMMA", "DELTA"]);
+        assert!(split_joint_venture("SUNWEST CONST. & DEV. CORP.").is_empty());
+        assert!(split_joint_venture("ALPHA JV").is_empty());
+    }
+}
@@ -0,0 +1,386 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::model::{CoordIssue, Project};
+
+/* One region of the reference table: the spelling used in the dataset, other accepted names and its main island group */
+pub struct RegionRef
+{
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub island: &'static str,
+}
+
+/* One province (or highly urbanized area reported like one), the region it belongs to and its approximate extent */
+pub struct ProvinceRef
+{
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub region: &'static str,
+    pub centroid: (f64, f64),   // (latitude, longitude)
+    pub radius_km: f64,         // distance from the centroid to the farthest part, outlying islands included
+}
+
+// Bounding box of the Philippines (Batanes to Tawi-Tawi, Palawan to Davao Oriental)
+pub const PH_LATITUDE: std::ops::RangeInclusive<f64> = 4.2..=21.5;
+pub const PH_LONGITUDE: std::ops::RangeInclusive<f64> = 116.0..=127.0;
+
+// Tolerance beyond a province's radius before a point counts as far from it (coastal and border projects)
+pub const PROVINCE_MARGIN_KM: f64 = 50.0;
+
+// Main island groups, as written in the MainIsland column
+pub const ISLANDS: [&str; 3] = ["Luzon", "Visayas", "Mindanao"];
+
+// Regions as in the PSGC, before the 2024 Negros Island Region; "Region " prefixes are ignored when matching
+pub const REGIONS: [RegionRef; 17] = [
+    RegionRef { name: "National Capital Region", aliases: &["NCR", "Metro Manila"], island: "Luzon" },
+    RegionRef { name: "Cordillera Administrative Region", aliases: &["CAR", "Cordillera"], island: "Luzon" },
+    RegionRef { name: "Region I", aliases: &["1", "Ilocos", "Ilocos Region"], island: "Luzon" },
+    RegionRef { name: "Region II", aliases: &["2", "Cagayan Valley"], island: "Luzon" },
+    RegionRef { name: "Region III", aliases: &["3", "Central Luzon"], island: "Luzon" },
+    RegionRef { name: "Region IV-A", aliases: &["4A", "CALABARZON"], island: "Luzon" },
+    RegionRef { name: "Region IV-B", aliases: &["4B", "MIMAROPA", "Southwestern Tagalog Region"], island: "Luzon" },
+    RegionRef { name: "Region V", aliases: &["5", "Bicol", "Bicol Region"], island: "Luzon" },
+    RegionRef { name: "Region VI", aliases: &["6", "Western Visayas"], island: "Visayas" },
+    RegionRef { name: "Region VII", aliases: &["7", "Central Visayas"], island: "Visayas" },
+    RegionRef { name: "Region VIII", aliases: &["8", "Eastern Visayas"], island: "Visayas" },
+    RegionRef { name: "Region IX", aliases: &["9", "Zamboanga Peninsula"], island: "Mindanao" },
+    RegionRef { name: "Region X", aliases: &["10", "Northern Mindanao"], island: "Mindanao" },
+    RegionRef { name: "Region XI", aliases: &["11", "Davao Region"], island: "Mindanao" },
+    RegionRef { name: "Region XII", aliases: &["12", "SOCCSKSARGEN"], island: "Mindanao" },
+    RegionRef { name: "Region XIII", aliases: &["13", "Caraga"], island: "Mindanao" },
+    RegionRef { name: "BARMM", aliases: &["Bangsamoro", "Bangsamoro Autonomous Region in Muslim Mindanao", "ARMM", "Autonomous Region in Muslim Mindanao"], island: "Mindanao" },
+];
+
+// Provinces by region; DPWH reports Metro Manila as one province
+pub const PROVINCES: [ProvinceRef; 86] = [
+    ProvinceRef { name: "Metropolitan Manila", aliases: &["Metro Manila", "Manila", "NCR"], region: "National Capital Region", centroid: (14.60, 121.00), radius_km: 20.0 },
+    ProvinceRef { name: "Abra", aliases: &[], region: "Cordillera Administrative Region", centroid: (17.60, 120.73), radius_km: 40.0 },
+    ProvinceRef { name: "Apayao", aliases: &[], region: "Cordillera Administrative Region", centroid: (18.01, 121.17), radius_km: 45.0 },
+    ProvinceRef { name: "Benguet", aliases: &[], region: "Cordillera Administrative Region", centroid: (16.55, 120.70), radius_km: 40.0 },
+    ProvinceRef { name: "Ifugao", aliases: &[], region: "Cordillera Administrative Region", centroid: (16.83, 121.17), radius_km: 35.0 },
+    ProvinceRef { name: "Kalinga", aliases: &[], region: "Cordillera Administrative Region", centroid: (17.47, 121.35), radius_km: 45.0 },
+    ProvinceRef { name: "Mountain Province", aliases: &["Mt. Province", "Mountain"], region: "Cordillera Administrative Region", centroid: (17.04, 121.11), radius_km: 35.0 },
+    ProvinceRef { name: "Ilocos Norte", aliases: &[], region: "Region I", centroid: (18.17, 120.75), radius_km: 50.0 },
+    ProvinceRef { name: "Ilocos Sur", aliases: &[], region: "Region I", centroid: (17.22, 120.57), radius_km: 70.0 },
+    ProvinceRef { name: "La Union", aliases: &[], region: "Region I", centroid: (16.50, 120.40), radius_km: 35.0 },
+    ProvinceRef { name: "Pangasinan", aliases: &[], region: "Region I", centroid: (15.90, 120.33), radius_km: 70.0 },
+    ProvinceRef { name: "Batanes", aliases: &[], region: "Region II", centroid: (20.45, 121.97), radius_km: 60.0 },
+    ProvinceRef { name: "Cagayan", aliases: &[], region: "Region II", centroid: (18.25, 121.80), radius_km: 110.0 },
+    ProvinceRef { name: "Isabela", aliases: &[], region: "Region II", centroid: (16.97, 121.81), radius_km: 80.0 },
+    ProvinceRef { name: "Nueva Vizcaya", aliases: &[], region: "Region II", centroid: (16.33, 121.17), radius_km: 45.0 },
+    ProvinceRef { name: "Quirino", aliases: &[], region: "Region II", centroid: (16.27, 121.54), radius_km: 40.0 },
+    ProvinceRef { name: "Aurora", aliases: &[], region: "Region III", centroid: (15.98, 121.63), radius_km: 80.0 },
+    ProvinceRef { name: "Bataan", aliases: &[], region: "Region III", centroid: (14.64, 120.48), radius_km: 30.0 },
+    ProvinceRef { name: "Bulacan", aliases: &[], region: "Region III", centroid: (14.97, 120.95), radius_km: 35.0 },
+    ProvinceRef { name: "Nueva Ecija", aliases: &[], region: "Region III", centroid: (15.58, 121.00), radius_km: 55.0 },
+    ProvinceRef { name: "Pampanga", aliases: &[], region: "Region III", centroid: (15.08, 120.67), radius_km: 35.0 },
+    ProvinceRef { name: "Tarlac", aliases: &[], region: "Region III", centroid: (15.47, 120.58), radius_km: 40.0 },
+    ProvinceRef { name: "Zambales", aliases: &[], region: "Region III", centroid: (15.51, 120.07), radius_km: 90.0 },
+    ProvinceRef { name: "Batangas", aliases: &[], region: "Region IV-A", centroid: (13.92, 121.08), radius_km: 50.0 },
+    ProvinceRef { name: "Cavite", aliases: &[], region: "Region IV-A", centroid: (14.28, 120.87), radius_km: 30.0 },
+    ProvinceRef { name: "Laguna", aliases: &[], region: "Region IV-A", centroid: (14.17, 121.33), radius_km: 40.0 },
+    ProvinceRef { name: "Quezon", aliases: &[], region: "Region IV-A", centroid: (14.03, 122.11), radius_km: 150.0 },
+    ProvinceRef { name: "Rizal", aliases: &[], region: "Region IV-A", centroid: (14.60, 121.30), radius_km: 30.0 },
+    ProvinceRef { name: "Marinduque", aliases: &[], region: "Region IV-B", centroid: (13.40, 121.97), radius_km: 25.0 },
+    ProvinceRef { name: "Occidental Mindoro", aliases: &[], region: "Region IV-B", centroid: (13.10, 120.77), radius_km: 90.0 },
+    ProvinceRef { name: "Oriental Mindoro", aliases: &[], region: "Region IV-B", centroid: (13.05, 121.40), radius_km: 70.0 },
+    ProvinceRef { name: "Palawan", aliases: &[], region: "Region IV-B", centroid: (9.83, 118.74), radius_km: 280.0 },
+    ProvinceRef { name: "Romblon", aliases: &[], region: "Region IV-B", centroid: (12.58, 122.27), radius_km: 70.0 },
+    ProvinceRef { name: "Albay", aliases: &[], region: "Region V", centroid: (13.18, 123.53), radius_km: 35.0 },
+    ProvinceRef { name: "Camarines Norte", aliases: &[], region: "Region V", centroid: (14.14, 122.76), radius_km: 45.0 },
+    ProvinceRef { name: "Camarines Sur", aliases: &[], region: "Region V", centroid: (13.53, 123.35), radius_km: 70.0 },
+    ProvinceRef { name: "Catanduanes", aliases: &[], region: "Region V", centroid: (13.71, 124.24), radius_km: 35.0 },
+    ProvinceRef { name: "Masbate", aliases: &[], region: "Region V", centroid: (12.17, 123.58), radius_km: 90.0 },
+    ProvinceRef { name: "Sorsogon", aliases: &[], region: "Region V", centroid: (12.99, 124.01), radius_km: 45.0 },
+    ProvinceRef { name: "Aklan", aliases: &[], region: "Region VI", centroid: (11.58, 122.43), radius_km: 45.0 },
+    ProvinceRef { name: "Antique", aliases: &[], region: "Region VI", centroid: (11.37, 122.05), radius_km: 90.0 },
+    ProvinceRef { name: "Capiz", aliases: &[], region: "Region VI", centroid: (11.39, 122.63), radius_km: 45.0 },
+    ProvinceRef { name: "Guimaras", aliases: &[], region: "Region VI", centroid: (10.59, 122.63), radius_km: 20.0 },
+    ProvinceRef { name: "Iloilo", aliases: &[], region: "Region VI", centroid: (10.72, 122.56), radius_km: 90.0 },
+    ProvinceRef { name: "Negros Occidental", aliases: &[], region: "Region VI", centroid: (10.29, 123.00), radius_km: 110.0 },
+    ProvinceRef { name: "Bohol", aliases: &[], region: "Region VII", centroid: (9.85, 124.14), radius_km: 50.0 },
+    ProvinceRef { name: "Cebu", aliases: &[], region: "Region VII", centroid: (10.32, 123.75), radius_km: 140.0 },
+    ProvinceRef { name: "Negros Oriental", aliases: &[], region: "Region VII", centroid: (9.63, 123.01), radius_km: 90.0 },
+    ProvinceRef { name: "Siquijor", aliases: &[], region: "Region VII", centroid: (9.20, 123.59), radius_km: 15.0 },
+    ProvinceRef { name: "Biliran", aliases: &[], region: "Region VIII", centroid: (11.58, 124.47), radius_km: 20.0 },
+    ProvinceRef { name: "Eastern Samar", aliases: &[], region: "Region VIII", centroid: (11.50, 125.50), radius_km: 90.0 },
+    ProvinceRef { name: "Leyte", aliases: &[], region: "Region VIII", centroid: (10.86, 124.88), radius_km: 90.0 },
+    ProvinceRef { name: "Northern Samar", aliases: &[], region: "Region VIII", centroid: (12.36, 124.77), radius_km: 70.0 },
+    ProvinceRef { name: "Samar", aliases: &["Western Samar"], region: "Region VIII", centroid: (11.83, 124.95), radius_km: 80.0 },
+    ProvinceRef { name: "Southern Leyte", aliases: &[], region: "Region VIII", centroid: (10.33, 125.17), radius_km: 50.0 },
+    ProvinceRef { name: "Zamboanga del Norte", aliases: &[], region: "Region IX", centroid: (8.15, 123.00), radius_km: 110.0 },
+    ProvinceRef { name: "Zamboanga del Sur", aliases: &[], region: "Region IX", centroid: (7.84, 123.30), radius_km: 170.0 },
+    ProvinceRef { name: "Zamboanga Sibugay", aliases: &[], region: "Region IX", centroid: (7.52, 122.82), radius_km: 60.0 },
+    ProvinceRef { name: "City of Isabela", aliases: &["Isabela City"], region: "Region IX", centroid: (6.70, 121.97), radius_km: 15.0 },
+    ProvinceRef { name: "Bukidnon", aliases: &[], region: "Region X", centroid: (8.05, 125.09), radius_km: 80.0 },
+    ProvinceRef { name: "Camiguin", aliases: &[], region: "Region X", centroid: (9.17, 124.72), radius_km: 15.0 },
+    ProvinceRef { name: "Lanao del Norte", aliases: &[], region: "Region X", centroid: (7.87, 123.89), radius_km: 60.0 },
+    ProvinceRef { name: "Misamis Occidental", aliases: &[], region: "Region X", centroid: (8.34, 123.71), radius_km: 50.0 },
+    ProvinceRef { name: "Misamis Oriental", aliases: &[], region: "Region X", centroid: (8.50, 124.62), radius_km: 80.0 },
+    ProvinceRef { name: "Davao de Oro", aliases: &["Compostela Valley"], region: "Region XI", centroid: (7.31, 126.17), radius_km: 50.0 },
+    ProvinceRef { name: "Davao del Norte", aliases: &[], region: "Region XI", centroid: (7.56, 125.65), radius_km: 50.0 },
+    ProvinceRef { name: "Davao del Sur", aliases: &[], region: "Region XI", centroid: (6.77, 125.35), radius_km: 90.0 },
+    ProvinceRef { name: "Davao Occidental", aliases: &[], region: "Region XI", centroid: (6.10, 125.61), radius_km: 90.0 },
+    ProvinceRef { name: "Davao Oriental", aliases: &[], region: "Region XI", centroid: (7.32, 126.54), radius_km: 90.0 },
+    ProvinceRef { name: "Cotabato", aliases: &["North Cotabato"], region: "Region XII", centroid: (7.20, 124.85), radius_km: 80.0 },
+    ProvinceRef { name: "Sarangani", aliases: &[], region: "Region XII", centroid: (5.93, 125.46), radius_km: 80.0 },
+    ProvinceRef { name: "South Cotabato", aliases: &[], region: "Region XII", centroid: (6.27, 124.85), radius_km: 50.0 },
+    ProvinceRef { name: "Sultan Kudarat", aliases: &[], region: "Region XII", centroid: (6.51, 124.42), radius_km: 70.0 },
+    ProvinceRef { name: "Agusan del Norte", aliases: &[], region: "Region XIII", centroid: (8.95, 125.53), radius_km: 45.0 },
+    ProvinceRef { name: "Agusan del Sur", aliases: &[], region: "Region XIII", centroid: (8.15, 125.98), radius_km: 80.0 },
+    ProvinceRef { name: "Dinagat Islands", aliases: &["Dinagat"], region: "Region XIII", centroid: (10.13, 125.60), radius_km: 30.0 },
+    ProvinceRef { name: "Surigao del Norte", aliases: &[], region: "Region XIII", centroid: (9.76, 125.75), radius_km: 70.0 },
+    ProvinceRef { name: "Surigao del Sur", aliases: &[], region: "Region XIII", centroid: (8.54, 126.11), radius_km: 110.0 },
+    ProvinceRef { name: "Basilan", aliases: &[], region: "BARMM", centroid: (6.42, 122.00), radius_km: 40.0 },
+    ProvinceRef { name: "Lanao del Sur", aliases: &[], region: "BARMM", centroid: (7.82, 124.44), radius_km: 70.0 },
+    ProvinceRef { name: "Maguindanao", aliases: &[], region: "BARMM", centroid: (6.94, 124.42), radius_km: 70.0 },
+    ProvinceRef { name: "Maguindanao del Norte", aliases: &[], region: "BARMM", centroid: (7.22, 124.25), radius_km: 50.0 },
+    ProvinceRef { name: "Maguindanao del Sur", aliases: &[], region: "BARMM", centroid: (6.87, 124.45), radius_km: 60.0 },
+    ProvinceRef { name: "Sulu", aliases: &[], region: "BARMM", centroid: (5.97, 121.03), radius_km: 110.0 },
+    ProvinceRef { name: "Tawi-Tawi", aliases: &[], region: "BARMM", centroid: (5.13, 119.95), radius_km: 250.0 },
+    ProvinceRef { name: "Cotabato City", aliases: &[], region: "BARMM", centroid: (7.22, 124.25), radius_km: 15.0 },
+];
+
+/* Outcome of normalizing one project's Province, Region and MainIsland */
+#[derive(Debug, Default)]
+pub struct LocationCheck
+{
+    pub normalized: usize,          // values rewritten to the reference spelling
+    pub filled: usize,              // empty Region/MainIsland taken from the province or region
+    pub unknown: Vec<String>,       // values not in the reference table, e.g. "Province 'Xyz'"
+    pub conflicts: Vec<String>,     // e.g. "Province Cebu is in Region VII, not Region X"
+}
+
+// Uppercase letters and digits only, so "Tawi-Tawi", "TAWI TAWI" and "tawitawi" compare equal
+fn key(name: &str) -> String
+{
+    name.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_uppercase()
+}
+
+// The whole value, then each part around parentheses or a dash ("Region X (Northern Mindanao)", "Region X - Northern Mindanao")
+fn candidates(value: &str) -> Vec<&str>
+{
+    let mut parts = vec![value];
+    parts.extend(value.split(['(', ')', '–', '—']).flat_map(|p| p.split(" - ")).map(str::trim).filter(|p| !p.is_empty()));
+    parts
+}
+
+/* The region a value names: "Region X", "Region 10", "X", "Northern Mindanao" and "REGION-X" all find Region X */
+pub fn find_region(value: &str) -> Option<&'static RegionRef>
+{
+    let region_key = |name: &str| {
+        let k = key(name);
+        match k.strip_prefix("REGION") {
+            Some(rest) if !rest.is_empty() => rest.to_string(),
+            _ => k,
+        }
+    };
+    candidates(value).into_iter().find_map(|candidate| {
+        let wanted = region_key(candidate);
+        REGIONS.iter().find(|r| region_key(r.name) == wanted || r.aliases.iter().any(|a| region_key(a) == wanted))
+    })
+}
+
+/* The province a value names, ignoring case, spacing and punctuation ("Province of " is optional) */
+pub fn find_province(value: &str) -> Option<&'static ProvinceRef>
+{
+    candidates(value).into_iter().find_map(|candidate| {
+        let k = key(candidate);
+        let wanted = k.strip_prefix("PROVINCEOF").filter(|rest| !rest.is_empty()).unwrap_or(&k);
+        PROVINCES.iter().find(|p| key(p.name) == wanted || p.aliases.iter().any(|a| key(a) == wanted))
+    })
+}
+
+/* The main island group a value names, ignoring case */
+pub fn find_island(value: &str) -> Option<&'static str>
+{
+    ISLANDS.into_iter().find(|i| key(i) == key(value))
+}
+
+// Region of the reference table by its canonical name
+fn region_named(name: &str) -> Option<&'static RegionRef>
+{
+    REGIONS.iter().find(|r| r.name == name)
+}
+
+// Rewrites a field to its reference spelling; an unknown value is kept as it is and reported
+fn normalize_field<T>(field: &mut Option<String>, column: &str, find: impl Fn(&str) -> Option<T>, name: impl Fn(&T) -> &'static str, check: &mut LocationCheck) -> Option<T>
+{
+    let value = field.as_deref().map(str::trim).filter(|v| !v.is_empty())?;
+    match find(value) {
+        Some(found) => {
+            if value != name(&found) {
+                *field = Some(name(&found).to_string());
+                check.normalized += 1;
+            }
+            Some(found)
+        }
+        None => {
+            check.unknown.push(format!("{column} '{value}'"));
+            None
+        }
+    }
+}
+
+// Fills an empty field from the reference table
+fn fill(field: &mut Option<String>, value: &str, check: &mut LocationCheck)
+{
+    if field.as_deref().is_none_or(|v| v.trim().is_empty()) {
+        *field = Some(value.to_string());
+        check.filled += 1;
+    }
+}
+
+/* Rewrites Province, Region and MainIsland to their reference spelling, fills an empty Region or MainIsland
+   from the province or region, and reports combinations that contradict the reference table.
+   Values the table does not know are left unchanged. */
+pub fn normalize_location(p: &mut Project) -> LocationCheck
+{
+    let mut check = LocationCheck::default();
+    let province = normalize_field(&mut p.province, "Province", find_province, |f| f.name, &mut check);
+    let region = normalize_field(&mut p.region, "Region", find_region, |f| f.name, &mut check);
+    let island = normalize_field(&mut p.main_island, "MainIsland", find_island, |f| *f, &mut check);
+
+    // the region the province belongs to, unless the row names a different one
+    let province_region = province.and_then(|f| region_named(f.region));
+    if let (Some(f), Some(expected), Some(actual)) = (province, province_region, region)
+        && expected.name != actual.name
+    {
+        check.conflicts.push(format!("Province {} is in {}, not {}", f.name, expected.name, actual.name));
+    }
+    let effective_region = region.or(province_region);
+    if let (Some(r), Some(actual)) = (effective_region, island)
+        && r.island != actual
+    {
+        check.conflicts.push(format!("{} is in {}, not {}", r.name, r.island, actual));
+    }
+
+    if check.conflicts.is_empty() {
+        if let Some(r) = province_region {
+            fill(&mut p.region, r.name, &mut check);
+        }
+        if let Some(r) = effective_region {
+            fill(&mut p.main_island, r.island, &mut check);
+        }
+    }
+    check
+}
+
+/* Great-circle distance in kilometres between two (latitude, longitude) points */
+pub fn distance_km(a: (f64, f64), b: (f64, f64)) -> f64
+{
+    let (lat1, lon1, lat2, lon2) = (a.0.to_radians(), a.1.to_radians(), b.0.to_radians(), b.1.to_radians());
+    let h = ((lat2 - lat1) / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+    2.0 * 6371.0 * h.sqrt().asin()
+}
+
+fn in_country(lat: f64, lon: f64) -> bool
+{
+    PH_LATITUDE.contains(&lat) && PH_LONGITUDE.contains(&lon)
+}
+
+/* Checks an observed lat/lon pair, flags the project and repairs what can be repaired: swapped values are
+   exchanged back, zeros and points outside the country are cleared so imputation replaces them, and points far
+   from the declared province are kept. Runs after normalize_location. Returns the issue and a description. */
+pub fn check_coordinates(p: &mut Project) -> Option<(CoordIssue, String)>
+{
+    let (lat, lon) = (p.lat?, p.lon?);
+    let (issue, detail) = if lat == 0.0 || lon == 0.0 {
+        p.lat = None;
+        p.lon = None;
+        (CoordIssue::Zero, format!("{lat}, {lon}: zero coordinate"))
+    } else if !in_country(lat, lon) && in_country(lon, lat) {
+        (p.lat, p.lon) = (Some(lon), Some(lat));
+        (CoordIssue::Swapped, format!("{lat}, {lon}: latitude and longitude swapped"))
+    } else if !in_country(lat, lon) {
+        p.lat = None;
+        p.lon = None;
+        (CoordIssue::OutsideCountry, format!("{lat}, {lon}: outside the Philippines"))
+    } else {
+        let province = p.province.as_deref().and_then(find_province)?;
+        let km = distance_km((lat, lon), province.centroid);
+        if km <= province.radius_km + PROVINCE_MARGIN_KM {
+            return None;
+        }
+        (CoordIssue::FarFromProvince { km: km.round() as i64 }, format!("{lat}, {lon}: {km:.0} km from the {} centroid", province.name))
+    };
+
+    // one issue per project: a corrected swap is not also checked against the province
+    p.coord_issue = Some(issue);
+    Some((issue, detail))
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn project(province: &str, region: &str, island: &str) -> Project
+    {
+        let mut p = Project::new();
+        let text = |v: &str| (!v.is_empty()).then(|| v.to_string());
+        (p.province, p.region, p.main_island) = (text(province), text(region), text(island));
+        p
+    }
+
+    #[test]
+    fn spellings_are_normalized()
+    {
+        for value in ["Region X", "Region 10", "REGION-X", "Northern Mindanao", "Region X (Northern Mindanao)"] {
+            assert_eq!(find_region(value).map(|r| r.name), Some("Region X"), "{value}");
+        }
+        assert_eq!(find_province("TAWI TAWI").map(|p| p.name), Some("Tawi-Tawi"));
+        assert_eq!(find_province("Province of Cebu").map(|p| p.name), Some("Cebu"));
+        assert_eq!(find_island("luzon"), Some("Luzon"));
+
+        let mut p = project("bukidnon", "Region 10", "MINDANAO");
+        let check = normalize_location(&mut p);
+        assert_eq!((p.province.as_deref(), p.region.as_deref(), p.main_island.as_deref()), (Some("Bukidnon"), Some("Region X"), Some("Mindanao")));
+        assert_eq!(check.normalized, 3);
+        assert!(check.conflicts.is_empty() && check.unknown.is_empty());
+    }
+
+    #[test]
+    fn empty_region_and_island_are_filled()
+    {
+        let mut p = project("Cebu", "", "");
+        let check = normalize_location(&mut p);
+        assert_eq!((p.region.as_deref(), p.main_island.as_deref()), (Some("Region VII"), Some("Visayas")));
+        assert_eq!(check.filled, 2);
+    }
+
+    #[test]
+    fn contradictions_and_unknown_values_are_reported()
+    {
+        let mut p = project("Cebu", "Region X", "");
+        let check = normalize_location(&mut p);
+        assert_eq!(check.conflicts, ["Province Cebu is in Region VII, not Region X"]);
+        assert_eq!(p.main_island, None);    // nothing is filled from a contradictory row
+
+        let mut p = project("Atlantis", "Region X", "Visayas");
+        let check = normalize_location(&mut p);
+        assert_eq!(check.unknown, ["Province 'Atlantis'"]);
+        assert_eq!(p.province.as_deref(), Some("Atlantis"));
+        assert_eq!(check.conflicts, ["Region X is in Mindanao, not Visayas"]);
+    }
+
+    #[test]
+    fn implausible_coordinates_are_flagged()
+    {
+        let located = |province: &str, lat: f64, lon: f64| {
+            let mut p = project(province, "", "");
+            (p.lat, p.lon) = (Some(lat), Some(lon));
+            let issue = check_coordinates(&mut p).map(|(issue, _)| issue);
+            (issue, p.lat, p.lon)
+        };
+        assert_eq!(located("Cebu", 10.3, 123.9), (None, Some(10.3), Some(123.9)));
+        assert_eq!(located("Cebu", 0.0, 123.9), (Some(CoordIssue::Zero), None, None));
+        assert_eq!(located("Cebu", 123.9, 10.3), (Some(CoordIssue::Swapped), Some(10.3), Some(123.9)));
+        assert_eq!(located("Cebu", 35.7, 139.7), (Some(CoordIssue::OutsideCountry), None, None));
+        assert!(matches!(located("Cebu", 15.0, 120.7).0, Some(CoordIssue::FarFromProvince { km }) if km > 500));
+    }
+}
@@ -0,0 +1,34 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::model::Project;
+use rayon::prelude::*;  // parallel iterators
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/* Groups projects by `key` in parallel; projects without a key are skipped.
+   Each group keeps the projects in their original order, so results do not depend on thread scheduling. */
+pub fn par_group_by<'a, K, F>(projects: &'a [Project], key: F) -> HashMap<K, Vec<&'a Project>>
+where
+    K: Eq + Hash + Send,
+    F: Fn(&Project) -> Option<K> + Sync,
+{
+    projects
+        .par_iter()
+        .fold(HashMap::new, |mut groups: HashMap<K, Vec<&'a Project>>, p| {
+            if let Some(k) = key(p) {
+                groups.entry(k).or_default().push(p);
+            }
+            groups
+        })
+        .reduce(HashMap::new, |mut left, right| {
+            // left holds earlier projects than right, so appending keeps the input order
+            for (k, mut group) in right {
+                left.entry(k).or_default().append(&mut group);
+            }
+            left
+        })
+}
@@ -0,0 +1,112 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::model::{CoordSource, Project};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/* Counts of what the imputation pass did, printed after loading */
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct ImputeStats
+{
+    pub observed: usize,
+    pub by_province: usize,
+    pub by_region: usize,
+    pub missing: usize,
+}
+
+// Running sums used to average coordinates of one province or region
+#[derive(Default)]
+struct Centroid
+{
+    lat_sum: f64,
+    lon_sum: f64,
+    count: usize,
+}
+
+impl Centroid
+{
+    fn add(&mut self, lat: f64, lon: f64)
+    {
+        self.lat_sum += lat;
+        self.lon_sum += lon;
+        self.count += 1;
+    }
+
+    fn average(&self) -> (f64, f64)
+    {
+        (self.lat_sum / self.count as f64, self.lon_sum / self.count as f64)
+    }
+}
+
+/* Grouping key: trimmed and uppercased so "Cebu " and "CEBU" land in the same group */
+fn group_key(name: &Option<String>) -> Option<String>
+{
+    let n = name.as_deref()?.trim();
+    if n.is_empty() {
+        return None;
+    }
+    Some(n.to_uppercase())
+}
+
+/* Both values present and within the valid lat/long ranges */
+fn observed_point(p: &Project) -> Option<(f64, f64)>
+{
+    match (p.lat, p.lon) {
+        (Some(lat), Some(lon)) if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) => Some((lat, lon)),
+        _ => None,
+    }
+}
+
+/* Fills missing lat/lon with provincial averages, falling back to regional averages (REQ-0005) */
+pub fn impute_coordinates(projects: &mut [Project]) -> ImputeStats
+{
+    // 1. Average the observed points per province and per region
+    let mut by_province: HashMap<String, Centroid> = HashMap::new();
+    let mut by_region: HashMap<String, Centroid> = HashMap::new();
+
+    for p in projects.iter() {
+        if let Some((lat, lon)) = observed_point(p) {
+            if let Some(key) = group_key(&p.province) {
+                by_province.entry(key).or_default().add(lat, lon);
+            }
+            if let Some(key) = group_key(&p.region) {
+                by_region.entry(key).or_default().add(lat, lon);
+            }
+        }
+    }
+
+    // 2. Fill the gaps, recording where each point came from
+    let mut stats = ImputeStats::default();
+    for p in projects.iter_mut() {
+        if observed_point(p).is_some() {
+            p.coord_source = CoordSource::Observed;
+            stats.observed += 1;
+            continue;
+        }
+
+        let province_avg = group_key(&p.province).and_then(|k| by_province.get(&k)).map(Centroid::average);
+        let region_avg = group_key(&p.region).and_then(|k| by_region.get(&k)).map(Centroid::average);
+
+        // partial or out-of-range pairs are replaced as a whole so lat and lon always come from the same source
+        (p.lat, p.lon, p.coord_source) = match (province_avg, region_avg) {
+            (Some((lat, lon)), _) => {
+                stats.by_province += 1;
+                (Some(lat), Some(lon), CoordSource::ImputedProvince)
+            }
+            (None, Some((lat, lon))) => {
+                stats.by_region += 1;
+                (Some(lat), Some(lon), CoordSource::ImputedRegion)
+            }
+            (None, None) => {
+                stats.missing += 1;
+                (None, None, CoordSource::Missing)
+            }
+        };
+    }
+
+    stats
+}
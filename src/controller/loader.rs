@@ -0,0 +1,751 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::{DelayBasis, RunConfig};
+use crate::controller::allocate::{self, Allocated, AllocationStats};
+use crate::controller::decompress::Source;
+use crate::controller::derived;
+use crate::controller::entity::{self, AliasEntry};
+use crate::controller::geography;
+use crate::controller::impute::{self, ImputeStats};
+use crate::controller::resolve::{ColumnResolver, MoneyCell, RefParser};
+use crate::controller::schema;
+use crate::controller::validation::{MAX_SAMPLES, RejectScope, RejectedRow, ValidationReport};
+use crate::controller::workbook;
+use crate::model::{DATE_CANDIDATES, DateReading, FormatTally, Project, Resolution, candidate_index, parse_float, parse_int, try_parse_date};
+use chrono::NaiveDate;
+use csv::StringRecord;
+use serde::{Deserialize, Serialize};    // Loaded is what the dataset snapshot stores
+use std::error::Error;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// Bump whenever parsing, cleaning or any Loaded field changes, so older snapshots are rebuilt
+pub const LOADER_VERSION: u32 = 16;
+
+// Derived fields in the order they are listed in the data-quality output
+const DERIVED_FIELDS: [&str; 3] = ["CostSavings", "CompletionDelayDays", "ScheduleSlippageDays"];
+
+// Date columns in the order of Columns::dates
+const DATE_COLUMNS: [&str; 3] = ["StartDate", "ActualCompletionDate", "TargetCompletionDate"];
+
+// Text columns that are only checked for empty values
+const TEXT_COLUMNS: [&str; 7] = ["ProjectId", "ContractId", "Region", "MainIsland", "Province", "Contractor", "TypeOfWork"];
+
+/* Position of every column the loader reads, resolved once from the header row (see schema::SCHEMA) */
+pub struct Columns
+{
+    pub project_id: Option<usize>,
+    pub contract_id: Option<usize>,
+    pub funding_year: Option<usize>,
+    pub region: Option<usize>,
+    pub main_island: Option<usize>,
+    pub province: Option<usize>,
+    pub contractor: Option<usize>,
+    pub type_of_work: Option<usize>,
+    pub budget: Option<usize>,
+    pub cost: Option<usize>,
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+    pub target: Option<usize>,
+    pub lat: Option<usize>,
+    pub lon: Option<usize>,
+}
+
+impl Columns
+{
+    /* Matches the header row against the schema; fails if a required column is missing */
+    pub fn from_headers(headers: &StringRecord) -> Result<Self, Box<dyn Error>>
+    {
+        let found = schema::match_headers(headers)?;
+        let find = |name: &str| found.get(name).copied();
+
+        Ok(Self
+        {
+            project_id: find("ProjectId"),
+            contract_id: find("ContractId"),
+            funding_year: find("FundingYear"),
+            region: find("Region"),
+            main_island: find("MainIsland"),
+            province: find("Province"),
+            contractor: find("Contractor"),
+            type_of_work: find("TypeOfWork"),
+            budget: find("ApprovedBudgetForContract"),
+            cost: find("ContractCost"),
+            start: find("StartDate"),
+            end: find("ActualCompletionDate"),
+            target: find("TargetCompletionDate"),
+            lat: find("ProjectLatitude"),
+            lon: find("ProjectLongitude"),
+        })
+    }
+
+    fn text(&self) -> [Option<usize>; 7]
+    {
+        [self.project_id, self.contract_id, self.region, self.main_island, self.province, self.contractor, self.type_of_work]
+    }
+
+    fn dates(&self) -> [Option<usize>; 3]
+    {
+        [self.start, self.end, self.target]
+    }
+}
+
+/* Trimmed field without allocating; "" when the column or the field is missing */
+fn field(record: &StringRecord, index: Option<usize>) -> &str
+{
+    index.and_then(|i| record.get(i)).unwrap_or("").trim()
+}
+
+fn owned(record: &StringRecord, index: Option<usize>) -> Option<String>
+{
+    Some(field(record, index).to_string())
+}
+
+/* One date column of one file, tallied while its rows stream past. Its dominant format is only known once the
+   file has been read, so every candidate format keeps the count and first samples of the values it does not read. */
+#[derive(Default)]
+struct DateTally
+{
+    formats: FormatTally,
+    off_format: [usize; DATE_CANDIDATES],   // unambiguous values each candidate does not read
+    samples: [Vec<(u64, String)>; DATE_CANDIDATES], // the first MAX_SAMPLES of them
+}
+
+impl DateTally
+{
+    fn add(&mut self, row: u64, raw: &str, reading: &DateReading)
+    {
+        self.formats.add_formats(reading.formats);
+        if reading.formats == 0 || reading.ambiguous {
+            return;     // unreadable, or parsed again once the format is known
+        }
+        for (c, samples) in self.samples.iter_mut().enumerate() {
+            if reading.formats & 1 << c == 0 {
+                self.off_format[c] += 1;
+                if samples.len() < MAX_SAMPLES {
+                    samples.push((row, raw.to_string()));
+                }
+            }
+        }
+    }
+}
+
+/* Output of one streaming load (the snapshot stores it before the window filter, see apply_window) */
+#[derive(Serialize, Deserialize)]
+pub struct Loaded
+{
+    pub headers: Vec<String>,   // merged header row of every input file
+    pub projects: Vec<Project>,
+    pub quality: ValidationReport,
+    pub rejected: Vec<RejectedRow>,    // positions only, in input order
+    pub allocation: AllocationStats,
+    pub imputed: ImputeStats,
+    pub aliases: Vec<AliasEntry>,  // how each raw contractor name was resolved
+    pub has_target: bool,
+}
+
+impl Loaded
+{
+    /* Keeps only the projects inside the configured analysis window (REQ-0003); returns how many were loaded */
+    pub fn apply_window(&mut self, config: &RunConfig) -> usize
+    {
+        let total = self.projects.len();
+        self.projects.retain(|p| config.window.contains(p));
+        total
+    }
+}
+
+/* One input file opened for reading: its header row and a stream of the data records below it */
+pub struct InputFile<'a>
+{
+    pub name: String,
+    pub headers: StringRecord,
+    pub records: Box<dyn Iterator<Item = Result<StringRecord, Box<dyn Error>>> + 'a>,
+}
+
+/* Expands the configured inputs in order: existing paths are kept as given, anything else with * ? or [ is a
+   glob pattern (matches sorted by name). A file named twice is loaded once. */
+pub fn input_files(config: &RunConfig) -> Result<Vec<PathBuf>, Box<dyn Error>>
+{
+    let mut files: Vec<PathBuf> = Vec::new();
+    for input in &config.inputs {
+        let pattern = input.to_string_lossy();
+        let matches = if input.exists() || !pattern.contains(['*', '?', '[']) {
+            vec![input.clone()]     // a missing plain path fails when it is opened
+        } else {
+            let mut found: Vec<PathBuf> = glob::glob(&pattern)?.collect::<Result<Vec<_>, _>>()?;
+            found.retain(|p| p.is_file());
+            found.sort();
+            if found.is_empty() {
+                return Err(format!("no file matches '{pattern}'").into());
+            }
+            found
+        };
+        for path in matches {
+            if !files.contains(&path) {
+                files.push(path);
+            }
+        }
+    }
+    if files.is_empty() {
+        return Err("no input file given".into());
+    }
+    Ok(files)
+}
+
+/* Reads a workbook sheet, starting at the configured header row */
+pub fn open_workbook(path: &Path, config: &RunConfig) -> Result<InputFile<'static>, Box<dyn Error>>
+{
+    let (headers, records) = workbook::read_sheet(path, config.sheet.as_deref(), config.header_row)?;
+    Ok(InputFile { name: path.display().to_string(), headers, records: Box::new(records.into_iter().map(Ok)) })
+}
+
+/* Streams CSV text (plain or already decompressed), starting at the configured header row */
+pub fn open_csv<'a>(name: &str, reader: impl Read + 'a, config: &RunConfig) -> Result<InputFile<'a>, Box<dyn Error>>
+{
+    let rdr = csv::ReaderBuilder::new()  // reads content of the input CSV (dpwh_flood_control_projects.csv by default)
+        .flexible(true) // allows rows with different number of fields/columns
+        .has_headers(false) // the header is taken at config.header_row below
+        .from_reader(reader);
+    let above = config.header_row.checked_sub(1).ok_or("the header row is 1-based")?;
+    let mut records = rdr.into_records().skip(above).map(|r| r.map_err(Box::<dyn Error>::from));
+    let headers = records.next().ok_or_else(|| format!("{name} has no row {}", config.header_row))??;
+    Ok(InputFile { name: name.to_string(), headers, records: Box::new(records) })
+}
+
+/* Loads every configured input as one dataset and applies the analysis window, as the binary does;
+   no snapshot and no console output */
+pub fn load_path(config: &RunConfig) -> Result<Loaded, Box<dyn Error>>
+{
+    let mut loaded = load_files(&input_files(config)?, config)?;
+    loaded.apply_window(config);
+    Ok(loaded)
+}
+
+/* Loads the given files (already expanded) as one dataset, opening each only when its turn comes and reading it once.
+   Workbooks are recognised by extension; any other file is CSV, gzip/zstd-compressed or alone in a zip archive. */
+pub fn load_files(files: &[PathBuf], config: &RunConfig) -> Result<Loaded, Box<dyn Error>>
+{
+    let mut builder = Builder::new(config)?;
+    for path in files {
+        with_input(path, config, |input| builder.add(input))?;
+    }
+    Ok(builder.finish())
+}
+
+/* Opens one input file and hands it to `read` */
+fn with_input<T>(path: &Path, config: &RunConfig, read: impl FnOnce(InputFile<'_>) -> Result<T, Box<dyn Error>>) -> Result<T, Box<dyn Error>>
+{
+    if workbook::is_workbook(path) {
+        read(open_workbook(path, config)?)
+    } else {
+        let mut source = Source::open(path)?;  // decompressed while it is read, never extracted to disk
+        read(open_csv(&path.display().to_string(), source.reader()?, config)?)
+    }
+}
+
+/* Streams the input files again and passes each rejected row to `write`, laid out under the merged header row.
+   The load keeps only the position of a rejected row, so its raw fields never sit in memory or in the snapshot.
+   `rejected` is in input order, as Loaded lists it; a file is read only up to its last rejected row. */
+pub fn read_rejected(files: &[PathBuf], headers: &[String], rejected: &[RejectedRow], config: &RunConfig, mut write: impl FnMut(&RejectedRow, Vec<String>) -> Result<(), Box<dyn Error>>) -> Result<(), Box<dyn Error>>
+{
+    let keys: Vec<String> = headers.iter().map(|h| schema::column_key(h)).collect();
+    let mut pending = rejected.iter().peekable();
+    for path in files {
+        with_input(path, config, |input| {
+            let slots = merged_slots(&keys, &input.headers);
+            for (index, result) in input.records.enumerate() {
+                if pending.peek().is_none_or(|r| r.source != input.name) {
+                    break;
+                }
+                let record = result?;
+                while let Some(r) = pending.next_if(|r| r.source == input.name && r.row == index as u64 + 1) {
+                    write(r, merged_fields(&record, &slots))?;
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/* Loads inputs that are already open (e.g. records produced in memory) as one dataset */
+pub fn load_records(inputs: Vec<InputFile<'_>>, config: &RunConfig) -> Result<Loaded, Box<dyn Error>>
+{
+    let mut builder = Builder::new(config)?;
+    for input in inputs {
+        builder.add(input)?;
+    }
+    Ok(builder.finish())
+}
+
+/* One input file's place in the merged dataset */
+struct FileLayout
+{
+    name: String,
+    cols: Columns,
+    dates: [DateTally; 3],  // in the order of Columns::dates
+    formats: [Option<&'static str>; 3], // dominant format of each date column, set once the whole file is read
+    first_row: u64,     // rows of the files before it: the file's row n is global row first_row + n
+    rows: u64,
+}
+
+/* Builds every Project in one pass per file. Only rows holding a cluster/MYCA reference keep their raw record,
+   for a short second pass once every referenced ID of every file has been seen. Likewise only the text of
+   ambiguous dates is kept, to be parsed once the file's date formats are known. */
+struct Builder<'a>
+{
+    config: &'a RunConfig,
+    ref_parser: RefParser,  // precompiled regexes for cluster/MYCA references
+    quality: ValidationReport,
+    budget_resolver: ColumnResolver,
+    cost_resolver: ColumnResolver,
+    projects: Vec<Project>,
+    readings: Vec<[DateReading; 3]>,    // date cells of each project, in the order of Columns::dates
+    ambiguous: Vec<(usize, usize, [Option<String>; 3])>,   // (project, file) of rows with an ambiguous date, and its text
+    deferred: Vec<(usize, usize, StringRecord)>,    // (project, file) of rows waiting for reference resolution
+    headers: Vec<String>,       // merged header row
+    header_keys: Vec<String>,   // schema::column_key of each merged header
+    files: Vec<FileLayout>,
+    reviewed_aliases: entity::ReviewedAliases,  // from config.contractor_aliases
+}
+
+impl<'a> Builder<'a>
+{
+    fn new(config: &'a RunConfig) -> Result<Self, Box<dyn Error>>
+    {
+        let reviewed_aliases = match &config.contractor_aliases {
+            Some(path) => entity::read_reviewed(path)?,
+            None => entity::ReviewedAliases::default(),
+        };
+        Ok(Self
+        {
+            config,
+            ref_parser: RefParser::new(),
+            quality: ValidationReport::new(""),
+            budget_resolver: ColumnResolver::new(),
+            cost_resolver: ColumnResolver::new(),
+            projects: Vec::new(),
+            readings: Vec::new(),
+            ambiguous: Vec::new(),
+            deferred: Vec::new(),
+            headers: Vec::new(),
+            header_keys: Vec::new(),
+            files: Vec::new(),
+            reviewed_aliases,
+        })
+    }
+
+    /* Places each header of a file in the merged header row: a column already seen in an earlier file
+       (under any accepted name) reuses its position, a new one is appended */
+    fn merge_headers(&mut self, headers: &StringRecord)
+    {
+        let mut slots: Vec<usize> = Vec::new();
+        for h in headers {
+            let key = schema::column_key(h);
+            let existing = (0..self.header_keys.len()).find(|&i| self.header_keys[i] == key && !slots.contains(&i));
+            let slot = existing.unwrap_or_else(|| {
+                self.headers.push(h.to_string());
+                self.header_keys.push(key);
+                self.headers.len() - 1
+            });
+            slots.push(slot);
+        }
+    }
+
+    /* Reads one file to the end; references may point into files read before or after it, and an ambiguous
+       date such as 03/04/2022 waits for the format inferred from every row of the file */
+    fn add(&mut self, input: InputFile<'_>) -> Result<(), Box<dyn Error>>
+    {
+        let InputFile { name, headers, records } = input;
+        let cols = Columns::from_headers(&headers).map_err(|e| format!("{name}: {e}"))?;
+        self.merge_headers(&headers);
+        let mut file = FileLayout { name, cols, dates: Default::default(), formats: [None; 3], first_row: self.projects.len() as u64, rows: 0 };
+
+        // 1. Single pass: build each Project, indexing its money cells for reference resolution
+        for result in records {
+            let record = result?;
+            let index = self.projects.len();
+            let row = index as u64 + 1;   // 1-based across every file, used to order samples and rejections
+            file.rows += 1;
+
+            let budget_cell = self.ref_parser.classify(field(&record, file.cols.budget));
+            let cost_cell = self.ref_parser.classify(field(&record, file.cols.cost));
+            let has_reference = matches!(budget_cell, MoneyCell::Reference(_)) || matches!(cost_cell, MoneyCell::Reference(_));
+
+            let (mut p, readings) = build_project(&record, &mut file, &mut self.quality, row);
+            p.source_file = file.name.clone();
+            p.source_row = file.rows;
+
+            // the text of an ambiguous date is kept; the row's date-derived fields wait for it
+            let raw = file.cols.dates().map(|i| field(&record, i));
+            let ambiguous: [Option<String>; 3] = std::array::from_fn(|d| readings[d].ambiguous.then(|| raw[d].to_string()));
+            if ambiguous.iter().all(Option::is_none) {
+                set_date_fields(&mut p, &file.cols, &mut self.quality, self.config, row);
+            } else {
+                self.ambiguous.push((index, self.files.len(), ambiguous));
+            }
+
+            if !has_reference {
+                let (budget, budget_resolution) = direct(&budget_cell);
+                let (cost, cost_resolution) = direct(&cost_cell);
+                set_money(&mut p, &record, &file.cols, &mut self.quality, row, Allocated { amount: budget, resolution: budget_resolution }, Allocated { amount: cost, resolution: cost_resolution });
+            }
+
+            let contract_id = field(&record, file.cols.contract_id);
+            let project_id = field(&record, file.cols.project_id);
+            self.budget_resolver.push(contract_id, project_id, budget_cell);
+            self.cost_resolver.push(contract_id, project_id, cost_cell);
+            self.projects.push(p);
+            self.readings.push(readings);
+            if has_reference {
+                self.deferred.push((index, self.files.len(), record)); // money is settled in the second pass
+            }
+        }
+        self.files.push(file);
+        Ok(())
+    }
+
+    fn finish(self) -> Loaded
+    {
+        let Builder { config, mut quality, budget_resolver, cost_resolver, mut projects, readings, ambiguous, deferred, headers, mut files, reviewed_aliases, .. } = self;
+        quality.total_rows = projects.len();
+
+        // 2. Date formats of each file, inferred from every one of its rows
+        for file in &mut files {
+            let columns = if file.cols.target.is_some() { 3 } else { 2 };
+            for (d, column) in DATE_COLUMNS.iter().enumerate().take(columns) {
+                let tally = &file.dates[d];
+                file.formats[d] = tally.formats.dominant();
+                quality.set_date_format(column, &file.name, file.formats[d]);
+                if let Some(c) = file.formats[d].and_then(candidate_index) {
+                    quality.record_off_format(column, &file.name, tally.off_format[c], &tally.samples[c]);
+                }
+            }
+            for index in file.first_row..file.first_row + file.rows {
+                let parsed = readings[index as usize].iter().zip(file.formats).map(|(r, format)| r.parsed(format).issue());
+                projects[index as usize].date_issue = parsed.max().flatten();
+            }
+        }
+        // ambiguous dates are parsed with their column's format, then their rows get the date-derived fields
+        for (index, file, raw) in ambiguous {
+            let row = index as u64 + 1;
+            let (layout, p) = (&files[file], &mut projects[index]);
+            for (d, raw) in raw.iter().enumerate() {
+                if let Some(raw) = raw {
+                    let parsed = try_parse_date(raw, layout.formats[d]);
+                    quality.record_date_issue(DATE_COLUMNS[d], &layout.name, row, raw, &parsed);
+                    *date_field(p, d) = parsed.date;
+                }
+            }
+            set_date_fields(p, &layout.cols, &mut quality, config, row);
+        }
+
+        // 3. Follow references and share each group's amount per the allocation policy (avoids double counting)
+        let (budgets, mut allocation) = allocate::allocate(&budget_resolver, &cost_resolver, config.allocation);
+        let (costs, cost_allocation) = allocate::allocate(&cost_resolver, &budget_resolver, config.allocation);
+        allocation.even_fallback += cost_allocation.even_fallback;  // groups and members are counted on the budget column
+
+        // parents may now hold only their share of the amount
+        for (p, (b, c)) in projects.iter_mut().zip(budgets.iter().zip(&costs)) {
+            if p.budget_resolution == Resolution::Direct {
+                p.approved_budget_for_contract = b.amount;
+            }
+            if p.cost_resolution == Resolution::Direct {
+                p.contract_cost = c.amount;
+            }
+            if let (Some(a), Some(c)) = (p.approved_budget_for_contract, p.contract_cost) {
+                p.cost_savings = Some(a - c);
+            }
+        }
+
+        // 4. Second pass over the deferred rows only
+        for (index, file, record) in deferred {
+            let row = index as u64 + 1;
+            let p = &mut projects[index];
+            set_money(p, &record, &files[file].cols, &mut quality, row, budgets[index].clone(), costs[index].clone());
+        }
+        let rejected = projects.iter().filter_map(|p| rejection(p, config)).collect();
+
+        // Fill missing coordinates before filtering so every province average uses the whole dataset
+        let imputed = impute::impute_coordinates(&mut projects);
+        // Contractor names are resolved over the whole dataset too, so an entity does not depend on the window
+        let aliases = entity::resolve(&mut projects, &reviewed_aliases);
+        quality.derived.sort_by_key(|d| DERIVED_FIELDS.iter().position(|f| *f == d.field));
+
+        quality.files = files.iter().map(|f| f.name.clone()).collect();
+        quality.source = quality.files.join(", ");
+        quality.localize_rows(|row| {
+            let file = files.iter().rev().find(|f| f.first_row < row).unwrap_or(&files[0]);
+            (file.name.clone(), row - file.first_row)
+        });
+
+        let has_target = files.iter().any(|f| f.cols.target.is_some());
+        Loaded { headers, projects, quality, rejected, allocation, imputed, aliases, has_target }
+    }
+}
+
+/* Amount of a cell that holds no reference */
+fn direct(cell: &MoneyCell) -> (Option<f64>, Resolution)
+{
+    match cell {
+        MoneyCell::Amount(v) => (Some(*v), Resolution::Direct),
+        _ => (None, Resolution::Missing),
+    }
+}
+
+/* Parses every non-money field. Dates are read as far as possible without their column's format:
+   an unambiguous date is final, an ambiguous one is settled in Builder::finish. */
+fn build_project(record: &StringRecord, file: &mut FileLayout, quality: &mut ValidationReport, row: u64) -> (Project, [DateReading; 3])
+{
+    let cols = &file.cols;
+    let mut p = Project::new();
+
+    // Text columns can only be empty
+    for (column, index) in TEXT_COLUMNS.iter().zip(cols.text()) {
+        if field(record, index).is_empty() {
+            quality.record_empty(column);
+        } else {
+            quality.track(column);
+        }
+    }
+
+    p.project_id = owned(record, cols.project_id);
+    let raw_year = field(record, cols.funding_year);
+    p.funding_year = parse_int(raw_year);
+    quality.check("FundingYear", row, raw_year, &p.funding_year);
+    p.region = owned(record, cols.region);
+    p.main_island = owned(record, cols.main_island);
+    p.province = owned(record, cols.province);
+    let location = geography::normalize_location(&mut p);  // reference spelling, filled Region/MainIsland
+    quality.record_location(row, &location);
+    p.contractor = owned(record, cols.contractor);
+    p.type_of_work = owned(record, cols.type_of_work);
+    p.contract_id = owned(record, cols.contract_id);
+    // money cells are checked once references are resolved; register them here to keep the column order
+    quality.track("ApprovedBudgetForContract");
+    quality.track("ContractCost");
+
+    // ----- Parse latitude & longitude -----
+    let raw_lat = field(record, cols.lat);
+    let raw_lon = field(record, cols.lon);
+    p.lat = parse_float(raw_lat);
+    p.lon = parse_float(raw_lon);
+    quality.check("ProjectLatitude", row, raw_lat, &p.lat);
+    quality.check("ProjectLongitude", row, raw_lon, &p.lon);
+    if let Some((issue, detail)) = geography::check_coordinates(&mut p) {
+        quality.record_coordinates(row, issue, &detail);
+    }
+
+    let mut readings = [DateReading::default(); 3];
+    for (d, index) in cols.dates().into_iter().enumerate() {
+        if index.is_none() && d == 2 {
+            continue;   // TargetCompletionDate is optional
+        }
+        let raw = field(record, index);
+        readings[d] = DateReading::read(raw);
+        quality.check(DATE_COLUMNS[d], row, raw, &readings[d].date);
+        file.dates[d].add(row, raw, &readings[d]);
+        *date_field(&mut p, d) = readings[d].date;
+    }
+
+    (p, readings)
+}
+
+/* Date of a project by its position in Columns::dates */
+fn date_field(p: &mut Project, d: usize) -> &mut Option<NaiveDate>
+{
+    match d {
+        0 => &mut p.start_date,
+        1 => &mut p.actual_completion_date,
+        _ => &mut p.target_completion_date,
+    }
+}
+
+/* Computes the date-derived fields once the project's dates are final */
+fn set_date_fields(p: &mut Project, cols: &Columns, quality: &mut ValidationReport, config: &RunConfig, row: u64)
+{
+    if cols.target.is_some() {
+        // Compute schedule slippage (actual minus target)
+        match (p.target_completion_date, p.actual_completion_date) {
+            (Some(t), Some(e)) => p.schedule_slippage_days = Some((e - t).num_days()),
+            (None, _) => quality.record_derived_failure("ScheduleSlippageDays", row, "missing TargetCompletionDate"),
+            (_, None) => quality.record_derived_failure("ScheduleSlippageDays", row, "missing ActualCompletionDate"),
+        }
+    }
+
+    // Compute completion delay days
+    match (p.start_date, p.actual_completion_date) {
+        (Some(s), Some(e)) => p.completion_delay_days = Some((e - s).num_days()),
+        (None, Some(_)) => quality.record_derived_failure("CompletionDelayDays", row, "missing StartDate"),
+        (Some(_), None) => quality.record_derived_failure("CompletionDelayDays", row, "missing ActualCompletionDate"),
+        (None, None) => quality.record_derived_failure("CompletionDelayDays", row, "missing StartDate and ActualCompletionDate"),
+    }
+
+    if let Some(issue) = derived::check_delay(p, &config.delay_bounds, config.delay_basis) {
+        quality.record_implausible(config.delay_basis.field(), row, &issue.describe(config.delay_basis));
+    }
+}
+
+/* Stores the (allocated) budget and cost, checks both cells and computes CostSavings */
+fn set_money(p: &mut Project, record: &StringRecord, cols: &Columns, quality: &mut ValidationReport, row: u64, budget: Allocated, cost: Allocated)
+{
+    check_money(quality, "ApprovedBudgetForContract", row, field(record, cols.budget), &budget.resolution);
+    p.approved_budget_for_contract = budget.amount;
+    p.budget_resolution = budget.resolution;
+
+    check_money(quality, "ContractCost", row, field(record, cols.cost), &cost.resolution);
+    p.contract_cost = cost.amount;
+    p.cost_resolution = cost.resolution;
+
+    // Compute cost savings; an amount left to the parent row by ParentOnly is not a failure
+    if let (Some(a), Some(c)) = (p.approved_budget_for_contract, p.contract_cost) {
+        p.cost_savings = Some(a - c);
+    }
+    else {
+        let missing = p.missing_amounts();
+        if !missing.is_empty() {
+            quality.record_derived_failure("CostSavings", row, &format!("missing {}", missing.join(" and ")));
+        }
+    }
+}
+
+/* Money columns may hold a cluster/MYCA reference instead of a number; a reference that resolved is not an error */
+fn check_money(quality: &mut ValidationReport, column: &str, row: u64, raw: &str, resolution: &Resolution)
+{
+    quality.record_resolution(resolution);
+    match resolution {
+        Resolution::Direct | Resolution::Referenced(_) => quality.track(column),
+        Resolution::Missing => quality.check::<f64>(column, row, raw, &None),  // empty or unparseable
+        _ => quality.record_unresolved(column, row, raw),
+    }
+}
+
+/* Why the row is (partly) excluded, for rejected_rows.csv; None if it is used everywhere */
+fn rejection(p: &Project, config: &RunConfig) -> Option<RejectedRow>
+{
+    let mut reasons: Vec<String> = Vec::new();
+    let mut scope = RejectScope::Partial;
+    if !config.window.contains(p) {
+        scope = RejectScope::Dropped;
+        reasons.push(match config.window.year_of(p) {
+            Some(year) => format!("out of window ({year})"),
+            None => format!("no {}", config.window.key.name()),
+        });
+    }
+    for (column, resolution) in [("ApprovedBudgetForContract", &p.budget_resolution), ("ContractCost", &p.cost_resolution)] {
+        if let Some(problem) = resolution.problem() {
+            reasons.push(format!("{problem} in {column} ({})", resolution.describe_path()));
+        }
+    }
+    if p.amount_on_parent() {
+        reasons.push("amount counted on its cluster/MYCA parent (parent-only)".to_string());
+    }
+    else if p.cost_savings.is_none() {
+        reasons.push("no CostSavings".to_string());
+    }
+    let basis = config.delay_basis;
+    if let (Some(issue), None) = (p.delay_issue, p.delay_days(basis)) {
+        reasons.push(format!("implausible {}", issue.describe(basis)));     // excluded by the delay policy
+    }
+    else if p.delay_days(basis).is_none() {
+        reasons.push(format!("no {}", basis.field()));
+    }
+    if basis == DelayBasis::Slippage && p.completion_delay_days.is_none() {
+        reasons.push("no CompletionDelayDays".to_string());
+    }
+
+    if reasons.is_empty() {
+        return None;
+    }
+    Some(RejectedRow { source: p.source_file.clone(), row: p.source_row, scope, reasons })
+}
+
+/* Merged header position of each of a file's columns, as Builder::merge_headers placed them: the first position
+   under the same column key that the file has not taken yet */
+fn merged_slots(keys: &[String], headers: &StringRecord) -> Vec<usize>
+{
+    let mut slots: Vec<usize> = Vec::new();
+    for h in headers {
+        let key = schema::column_key(h);
+        let slot = (0..keys.len()).find(|&i| keys[i] == key && !slots.contains(&i)).unwrap_or(keys.len());
+        slots.push(slot);
+    }
+    slots
+}
+
+/* The record laid out under the merged header row */
+fn merged_fields(record: &StringRecord, slots: &[usize]) -> Vec<String>
+{
+    // same layout as the merged header (always true for the first file): keep every field as read
+    if slots.iter().enumerate().all(|(i, &slot)| i == slot) {
+        return record.iter().map(str::to_string).collect();
+    }
+    let mut fields = vec![String::new(); slots.iter().max().map_or(0, |m| m + 1)];
+    for (value, &slot) in record.iter().zip(slots) {
+        fields[slot] = value.to_string();
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn input(name: &str, headers: &[&str], rows: &[&[&str]]) -> InputFile<'static>
+    {
+        let records: Vec<StringRecord> = rows.iter().map(|r| StringRecord::from(r.to_vec())).collect();
+        InputFile { name: name.to_string(), headers: StringRecord::from(headers.to_vec()), records: Box::new(records.into_iter().map(Ok)) }
+    }
+
+    const HEADERS: [&str; 12] = [
+        "ProjectId", "ContractId", "FundingYear", "Region", "MainIsland", "Province", "Contractor", "TypeOfWork",
+        "ApprovedBudgetForContract", "ContractCost", "StartDate", "ActualCompletionDate",
+    ];
+
+    #[test]
+    fn files_with_reordered_and_extra_headers_are_merged_by_column()
+    {
+        let first = input("a.csv", &HEADERS, &[&["P1", "C1", "2022", "Region X", "Mindanao", "Bukidnon", "ACME", "Dike", "100", "90", "2022-01-01", "2022-02-01"]]);
+        // same columns in another order, one under an alias, plus a column of its own
+        let second = input(
+            "b.csv",
+            &["Remarks", "Contract Amount", "ProjectId", "ContractId", "FundingYear", "Region", "MainIsland", "Province", "Contractor", "TypeOfWork", "ApprovedBudgetForContract", "StartDate", "ActualCompletionDate"],
+            &[&["late", "40", "P2", "C2", "2023", "Region X", "Mindanao", "Bukidnon", "ACME", "Dike", "50", "2023-01-01", "2023-03-01"]],
+        );
+        let loaded = load_records(vec![first, second], &RunConfig::new()).unwrap();
+
+        let mut expected: Vec<&str> = HEADERS.to_vec();
+        expected.push("Remarks");
+        assert_eq!(loaded.headers, expected);
+        let p = &loaded.projects[1];
+        assert_eq!((p.project_id.as_deref(), p.contract_cost, p.cost_savings), (Some("P2"), Some(40.0), Some(10.0)));
+        assert_eq!((p.source_file.as_str(), p.source_row), ("b.csv", 1));
+
+        // rejected_rows.csv lays the second file's fields out the same way
+        let keys: Vec<String> = loaded.headers.iter().map(|h| schema::column_key(h)).collect();
+        let b_headers = StringRecord::from(vec!["Remarks", "Contract Amount", "ProjectId"]);
+        let slots = merged_slots(&keys, &b_headers);
+        assert_eq!(slots, [12, 9, 0]);
+        let fields = merged_fields(&StringRecord::from(vec!["late", "40", "P2"]), &slots);
+        assert_eq!((fields.len(), fields[0].as_str(), fields[9].as_str(), fields[12].as_str()), (13, "P2", "40", "late"));
+    }
+
+    #[test]
+    fn a_header_row_of_zero_is_an_error()
+    {
+        let mut config = RunConfig::new();
+        config.header_row = 0;
+        let opened = open_csv("inline", "ProjectId\nP1\n".as_bytes(), &config);
+        assert_eq!(opened.err().map(|e| e.to_string()).as_deref(), Some("the header row is 1-based"));
+
+        config.header_row = 2;
+        let input = open_csv("inline", "Title\nProjectId\nP1\n".as_bytes(), &config).unwrap();
+        assert_eq!(input.headers.get(0), Some("ProjectId"));
+    }
+}
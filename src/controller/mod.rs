@@ -0,0 +1,166 @@
+pub mod allocate;
+pub mod decompress;
+pub mod derived;
+pub mod entity;
+pub mod geography;
+pub mod group;
+pub mod impute;
+pub mod loader;
+pub mod registry;
+pub mod report1;
+pub mod report2;
+pub mod report3;
+pub mod resolve;
+pub mod schema;
+pub mod snapshot;
+pub mod summary;
+pub mod tabular;
+pub mod validation;
+pub mod workbook;
+
+use crate::config::{AllocationPolicy, DelayBasis, RunConfig}; // imports run configuration (input path, output directory, delay semantics)
+use crate::model::Project; // imports Project from model
+use crate::view::{aliases, quality, rejected};  // imports data-quality output from view
+use rayon::prelude::*;  // runs the selected reports concurrently
+use std::error::Error;  // allows Result<(), Box<dyn Error>> (error handling)
+use std::io::Write;
+use loader::Loaded;  // streaming CSV ingestion
+use registry::{DynReport, ReportRegistry};   // reports available to generate_reports
+use snapshot::SnapshotKey;  // binary cache of the cleaned dataset
+use validation::{RejectScope, ValidationReport};   // per-column data-quality findings and rejected rows
+
+
+/* Everything produced by one load: the filtered projects and the data-quality findings */
+pub struct Dataset
+{
+    pub projects: Vec<Project>,
+    pub quality: ValidationReport,
+}
+
+// ----- Load File -----
+pub fn load_file(config: &RunConfig) -> Result<Dataset, Box<dyn Error>> {
+    
+    print!("Processing dataset {}...", config.describe_inputs());
+    std::io::stdout().flush()?; // ensure prompt prints immediately
+
+    // Reuse the cleaned dataset if the input files, the loader and the load settings are unchanged
+    let files = loader::input_files(config)?;  // globs expanded
+    let key = SnapshotKey::for_run(&files, config)?;
+    let snapshot_path = snapshot::snapshot_path(&files, config);
+    let cached = if config.use_snapshot { snapshot::read_snapshot(&snapshot_path, &key) } else { None };
+
+    let loaded = match cached {
+        Some(loaded) => {
+            print!(" reusing {}", snapshot_path.display());
+            loaded
+        }
+        None => {
+            // A quick pass over each file's date columns, then every Project is built in one streaming pass;
+            // only rows with cluster/MYCA references are revisited
+            let loaded = loader::load_files(&files, config)?;
+            if config.use_snapshot {
+                std::fs::create_dir_all(&config.output_dir)?;
+                if let Err(e) = snapshot::write_snapshot(&snapshot_path, &key, &loaded) {
+                    print!(" (warning: could not write {}: {e})", snapshot_path.display());  // the load itself succeeded
+                }
+            }
+            loaded
+        }
+    };
+    let mut loaded = loaded;
+    // Filter projects by the configured analysis window (2021–2023 by StartDate unless overridden), as load_path does
+    let total = loaded.apply_window(config);
+    let Loaded { headers, projects, quality, rejected, allocation, imputed, aliases, has_target } = loaded;
+    let filtered = projects.len();
+    let from = if files.len() > 1 { format!(" from {} files", files.len()) } else { String::new() };
+    println!(" ({total} rows loaded{from}, {filtered} filtered for {})", config.window.describe());
+    let refs = &quality.references;
+    println!(
+        "References: {} resolved ({} through several hops), {} dangling, {} without amount, {} cyclic",
+        refs.resolved, refs.multi_hop, refs.dangling, refs.no_value, refs.cycles
+    );
+    let fallback = if config.allocation == AllocationPolicy::ProRata {
+        format!("; {} budget/cost shares split evenly, no weight of their own", allocation.even_fallback)
+    } else {
+        String::new()
+    };
+    println!(
+        "Clustered/MYCA groups: {} sharing their amount with {} rows ({}{fallback})",
+        allocation.groups, allocation.members, config.allocation.name()
+    );
+    if !has_target && config.delay_basis == DelayBasis::Slippage {
+        println!("Warning: no TargetCompletionDate column, schedule slippage cannot be computed");
+    }
+    for d in &quality.dates {
+        let file = if files.len() > 1 { format!(" in {}", d.file) } else { String::new() };
+        println!(
+            "{}{file}: read as {} ({} ambiguous, {} inconsistent with that format)",
+            d.column,
+            d.dominant_format.as_deref().unwrap_or("unknown"),
+            d.ambiguous,
+            d.inconsistent
+        );
+    }
+    let places = &quality.locations;
+    println!(
+        "Locations: {} values normalized, {} filled from the reference table, {} unknown, {} rows inconsistent",
+        places.normalized, places.filled, places.unknown, places.inconsistent
+    );
+    println!(
+        "Coordinates: {} observed, {} imputed by province, {} imputed by region, {} missing",
+        imputed.observed, imputed.by_province, imputed.by_region, imputed.missing
+    );
+    let points = &quality.coordinates;
+    println!(
+        "Flagged coordinates: {} zero, {} swapped (corrected), {} outside the Philippines, {} far from their province",
+        points.zero, points.swapped, points.outside_country, points.far_from_province
+    );
+
+    std::fs::create_dir_all(&config.output_dir)?;
+    quality::export_quality(&quality, config)?;   // data_quality.json / .csv are written on every load
+    rejected::export_rejected(&headers, &rejected, &files, config)?;
+    let aliases_path = aliases::export_aliases(&aliases, config)?;
+    let entities = aliases.iter().map(|a| a.canonical.as_str()).collect::<std::collections::HashSet<&str>>().len();
+    let fuzzy = aliases.iter().filter(|a| a.suggestion.is_some()).count();
+    println!(
+        "Contractors: {} names resolved to {} entities ({} fuzzy suggestions to review; see {})",
+        aliases.len(),
+        entities,
+        fuzzy,
+        aliases_path.display()
+    );
+    let dropped = rejected.iter().filter(|r| r.scope == RejectScope::Dropped).count();
+    println!(
+        "Rejected rows: {} dropped, {} excluded from some metrics (see {})",
+        dropped,
+        rejected.len() - dropped,
+        config.output_path("rejected_rows.csv").display()
+    );
+
+    Ok(Dataset { projects, quality })
+}
+
+// ----- Generate Reports -----
+/* Runs the named reports (every report in `registry` if `names` is empty) */
+pub fn generate_reports(projects: &[Project], registry: &ReportRegistry, names: &[String], config: &RunConfig) -> Result<(), Box<dyn Error>> {
+    println!("\nGenerating reports...");
+    std::fs::create_dir_all(&config.output_dir)?;   // make sure the output directory exists
+    // iterate over the registry (not names) so output order never depends on how the reports were requested
+    let selected: Vec<&dyn DynReport> = registry
+        .iter()
+        .filter(|r| names.is_empty() || names.iter().any(|n| n.trim().eq_ignore_ascii_case(r.name())))
+        .collect();
+
+    // Each report writes its own files and returns its console text; errors become Strings to cross threads
+    let outputs: Vec<Result<String, String>> = selected
+        .par_iter()
+        .map(|report| report.run(projects, config).map_err(|e| format!("{}: {e}", report.name())))
+        .collect();
+
+    // collect keeps the order of `selected`, so the console output is the same as a sequential run
+    for output in outputs {
+        print!("{}", output?);
+    }
+    println!("\nAll reports generated.");
+    Ok(())
+}
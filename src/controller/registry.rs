@@ -0,0 +1,150 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::{OutputFormat, RunConfig};
+use crate::controller::tabular::{ReportResult, TableRow};
+use crate::model::Project;
+use crate::view::{self, render};
+use serde::Serialize;
+use std::error::Error;
+use std::fmt::{self, Write};
+
+/* A tabular report: computes typed rows from the filtered projects. Rendering to the terminal,
+   CSV and JSON is provided; override heading/render_table to change the console layout. */
+pub trait Report: Sync
+{
+    type Row: TableRow + Serialize + Send;
+
+    fn name(&self) -> &'static str;         // used by --only and the menu
+    fn description(&self) -> &'static str;  // one line shown in the help and the menu
+    fn file_stem(&self) -> &'static str;    // output files are <stem>.csv / <stem>.json
+
+    fn columns(&self) -> &'static [&'static str]
+    {
+        <Self::Row as TableRow>::COLUMNS
+    }
+
+    fn compute(&self, projects: &[Project], config: &RunConfig) -> ReportResult<Self::Row>;
+
+    // Console text printed before the table
+    fn heading(&self, result: &ReportResult<Self::Row>) -> String
+    {
+        format!("\n{}\n{}\n\n", result.title, result.settings)
+    }
+
+    // Terminal table; the default aligns the CSV fields under their column names
+    fn render_table(&self, result: &ReportResult<Self::Row>) -> Result<String, fmt::Error>
+    {
+        let records: Vec<Vec<String>> = result.rows.iter().map(|r| r.record()).collect();
+        render::plain_table(self.columns(), &records)
+    }
+}
+
+/* Object-safe form of a report, as stored in the registry; every Report gets it for free */
+pub trait DynReport: Sync
+{
+    fn name(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    fn columns(&self) -> &'static [&'static str];
+
+    // Computes the report, writes its files and returns its console output
+    fn run(&self, projects: &[Project], config: &RunConfig) -> Result<String, Box<dyn Error>>;
+}
+
+impl<T: Report> DynReport for T
+{
+    fn name(&self) -> &'static str
+    {
+        Report::name(self)
+    }
+
+    fn description(&self) -> &'static str
+    {
+        Report::description(self)
+    }
+
+    fn columns(&self) -> &'static [&'static str]
+    {
+        Report::columns(self)
+    }
+
+    fn run(&self, projects: &[Project], config: &RunConfig) -> Result<String, Box<dyn Error>>
+    {
+        let result = self.compute(projects, config);
+        let mut out = self.heading(&result);
+        if config.formats.contains(&OutputFormat::Table) {
+            out.push_str(&self.render_table(&result)?);
+        }
+
+        let written = render::export_files(&result, self.file_stem(), config)?;
+        if !written.is_empty() {
+            writeln!(out, "(Full table exported to {})\n", render::describe_paths(&written))?;
+        }
+        Ok(out)
+    }
+}
+
+/* Reports available to the menu and the command line, in the order they are generated */
+pub struct ReportRegistry
+{
+    reports: Vec<Box<dyn DynReport>>,
+}
+
+impl ReportRegistry
+{
+    // Empty registry, for tools that only want their own reports
+    pub fn new() -> Self
+    {
+        Self
+        {
+            reports: Vec::new(),
+        }
+    }
+
+    /* The reports shipped with the pipeline (REQ-0006 to REQ-0009) */
+    pub fn builtin() -> Self
+    {
+        let mut registry = Self::new();
+        registry.register(view::report1::RegionalEfficiency);
+        registry.register(view::report2::TopContractors);
+        registry.register(view::report3::AnnualTrends);
+        registry.register(view::summary::Summary);
+        registry
+    }
+
+    /* Adds a report after the existing ones; a report with the same name is replaced in place */
+    pub fn register(&mut self, report: impl DynReport + 'static)
+    {
+        match self.reports.iter().position(|r| r.name() == report.name()) {
+            Some(i) => self.reports[i] = Box::new(report),
+            None => self.reports.push(Box::new(report)),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn DynReport>
+    {
+        let name = name.trim();
+        self.reports.iter().find(|r| r.name().eq_ignore_ascii_case(name)).map(|r| r.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynReport>
+    {
+        self.reports.iter().map(|r| r.as_ref())
+    }
+
+    pub fn names(&self) -> Vec<&'static str>
+    {
+        self.reports.iter().map(|r| r.name()).collect()
+    }
+}
+
+impl Default for ReportRegistry
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,157 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::controller::group::par_group_by; // parallel group-by
+use crate::controller::tabular::{ReportResult, TableRow};
+use crate::model::{Project, median, round2};
+use rayon::prelude::*;  // computes each group's metrics in parallel
+use serde::Serialize;   // rows can be exported as JSON
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report1Row
+{
+    pub region: String,
+    pub main_island: String,
+    pub total_budget: f64,
+    pub median_savings: f64,
+    pub avg_delay: f64,
+    pub high_delay_pct: f64,
+    pub efficiency_score: f64,
+}
+
+/* Efficiency metrics per Region + MainIsland, sorted by EfficiencyScore (descending, normalized to 0-100) */
+pub fn regional_efficiency(projects: &[Project], config: &RunConfig) -> ReportResult<Report1Row>
+{
+    // Group by Region + MainIsland
+    let by_group = par_group_by(projects, |p| {
+        let project_region = p.region.clone().unwrap_or_else(|| "Unknown".to_string());
+        let project_island = p.main_island.clone().unwrap_or_else(|| "Unknown".to_string());
+        Some(format!("{}|{}", project_region, project_island)) // combines region and island as a key
+    });
+
+    // stores rows for report. Each row will hold metrics for one region + main island group
+    let mut rows: Vec<Report1Row> = by_group.into_par_iter().map(|(key, group)|
+    {
+        let parts: Vec<&str> = key.split('|').collect();    // splits key back into region and island
+        let region = parts[0].to_string();  // .to_string() converts &str to String
+        let main_island = parts[1].to_string();
+
+        let total_budget: f64 = group
+            .iter() // iterates through projects in group
+            .filter_map(|p| p.approved_budget_for_contract) // extracts approved_budget_for_contract if Some, skips if None
+            .sum(); // sums up all budgets
+        // same (more or less) logic for savings and delays
+
+        let mut savings: Vec<f64> = group
+            .iter()
+            .filter_map(|p| p.cost_savings)
+            .collect();
+        let median_savings = median(&mut savings);
+
+        let delays: Vec<f64> = group
+            .iter()
+            .filter_map(|p| p.delay_days(config.delay_basis).map(|d| d as f64))    // duration or slippage, per config
+            .collect();
+
+        let avg_delay = if delays.is_empty() {
+            0.0
+        }
+        else {
+            delays.iter().sum::<f64>() / delays.len() as f64    // sum of delays / count of delays = average delay in days
+        };
+
+        let high_delay_count = delays.iter().filter(|&&d| d > 30.0).count();    // counts number of delays greater than 30 days
+        let high_delay_pct = if delays.is_empty() {
+            0.0
+        }
+        else {
+            (high_delay_count as f64 / delays.len() as f64) * 100.0 // (count of high delays / total delays) * 100 = percentage of high delays
+        };
+
+        // --- EfficiencyRaw
+        let efficiency_raw = if avg_delay > 0.0 {
+            (median_savings / avg_delay) * 100.0
+        }
+        else {
+            0.0
+        };  // (if average delay > 0) (median savings / average delay) * 100
+
+        Report1Row
+        {
+            region,
+            main_island,
+            total_budget: round2(total_budget), // round2(i) rounds i to 2 decimal places
+            median_savings: round2(median_savings),
+            avg_delay: round2(avg_delay),
+            high_delay_pct: round2(high_delay_pct),
+            efficiency_score: efficiency_raw, // will normalize later
+        }
+    }).collect();
+
+    // --- Normalize efficiency scores to 0–100
+    let valid_scores: Vec<f64> = rows
+        .iter()
+        .filter(|r| r.efficiency_score > 0.0)
+        .map(|r| r.efficiency_score)
+        .collect(); // collects all efficiency scores greater than 0 into valid_scores vector
+
+    let (min_score, max_score) = if valid_scores.is_empty() {
+        (0.0, 1.0)
+    }   // if valid_scores is empty, set min to 0 and max to 1 to avoid division by zero
+    else {
+        (
+            valid_scores
+                .iter()
+                .cloned()   // clone to get f64 values instead of references
+                .fold(f64::INFINITY, f64::min), // finds minimum score in valid_scores
+            valid_scores
+                .iter()
+                .cloned()
+                .fold(f64::NEG_INFINITY, f64::max), // finds maximum score in valid_scores
+        )
+    };
+
+    // NORMALIZATION OF EFFICIENCY SCORES (normalized to 0–100)
+    for r in &mut rows {
+        if r.efficiency_score > 0.0 && (max_score - min_score) > f64::EPSILON {
+            r.efficiency_score = ((r.efficiency_score - min_score) / (max_score - min_score)) * 100.0;
+        }
+        else {
+            r.efficiency_score = 0.0;
+        }
+
+        r.efficiency_score = round2(r.efficiency_score);
+    }   // BASICALLY: normalized_score = ((raw_score - min) / (max - min)) * 100, assuming efficiency_score > 0 and max != min
+
+    // Sort descending by efficiency
+    rows.sort_by(|a, b| {
+        b.efficiency_score  // sorts rows in descending order by efficiency_score
+            .partial_cmp(&a.efficiency_score)   // use partial_cmp for f64 comparison (f64 can have NaN values)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    ReportResult::new("Regional Flood Mitigation Efficiency Summary", config, rows)
+}
+
+impl TableRow for Report1Row
+{
+    const COLUMNS: &'static [&'static str] =
+        &["Region", "MainIsland", "TotalBudget", "MedianSavings", "AvgDelay", "HighDelayPct", "EfficiencyScore"];
+
+    fn record(&self) -> Vec<String>
+    {
+        vec![
+            self.region.clone(),
+            self.main_island.clone(),
+            format!("{:.2}", self.total_budget),
+            format!("{:.2}", self.median_savings),
+            format!("{:.2}", self.avg_delay),
+            format!("{:.2}", self.high_delay_pct),
+            format!("{:.2}", self.efficiency_score),
+        ]
+    }
+}
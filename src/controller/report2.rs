@@ -0,0 +1,159 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::{JvAttribution, RunConfig};
+use crate::controller::tabular::{ReportResult, TableRow};
+use crate::model::{Project, round2};
+use rayon::prelude::*;  // computes each contractor's metrics in parallel
+use serde::Serialize;
+use std::cmp::Ordering; // enables Ordering for sorting
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Row
+{
+    pub contractor: String,
+    pub num_projects: usize,    // projects credited to the contractor, a shared joint-venture project counting as one (see top_contractors)
+    pub reliability_index: f64,
+    pub risk_flag: String,
+    pub total_cost: f64,
+    pub avg_delay: f64,
+    pub total_savings: f64,
+}
+
+/* The contractors credited with a project and the share of its amounts each one gets */
+pub fn credits(p: &Project, attribution: JvAttribution) -> Vec<(String, f64)>
+{
+    let members = &p.contractor_members;
+    match attribution {
+        JvAttribution::Full if !members.is_empty() => members.iter().map(|m| (m.clone(), 1.0)).collect(),
+        JvAttribution::Share if !members.is_empty() => members.iter().map(|m| (m.clone(), 1.0 / members.len() as f64)).collect(),
+        _ => vec![(p.contractor_entity.clone().unwrap_or_else(|| "Unknown".to_string()), 1.0)],   // the canonical name or "Unknown" if missing
+    }
+}
+
+/* Top 15 contractors by TotalCost among those with at least 5 projects */
+pub fn top_contractors(projects: &[Project], config: &RunConfig) -> ReportResult<Row>
+{
+    // Group by contractor entity, so spelling variants and former names of one firm are counted together;
+    // a joint venture's project goes to the joint venture or to its members, depending on config.jv_attribution
+    let credited: Vec<(String, f64, &Project)> = projects
+        .par_iter()
+        .flat_map_iter(|p| credits(p, config.jv_attribution).into_iter().map(move |(c, share)| (c, share, p)))
+        .collect();     // keeps the project order, so sums do not depend on thread scheduling
+    let mut by_contractor: HashMap<String, Vec<(f64, &Project)>> = HashMap::new();
+    for (contractor, share, p) in credited {
+        by_contractor.entry(contractor).or_default().push((share, p));
+    }
+
+    // stores rows for report. Each row will hold metrics for one contractor (only contractors with >=5 projects)
+    let mut rows: Vec<Row> = by_contractor.into_par_iter()
+        .filter(|(_, group)| group.len() >= 5)  // skip contractors with less than 5 projects
+        .map(|(contractor, group)|
+    {
+        // NumProjects and the 5-project minimum count a shared joint-venture project as one project for each member,
+        // since the member took part in it; the amounts and the average delay are weighted by the member's share
+        let weight: f64 = group.iter().map(|(share, _)| share).sum();  // number of projects, counting shared ones by share
+        let avg_delay = group
+            .iter()
+            .filter_map(|(share, p)| p.delay_days(config.delay_basis).map(|d| share * d as f64))    // gets the delay (duration or slippage) if exists
+            .sum::<f64>()
+            / weight;
+            // BASICALLY: avg_delay = total_delay_days / num_projects
+
+        let total_savings: f64 = group.iter().filter_map(|(share, p)| p.cost_savings.map(|s| share * s)).sum(); // sums up cost_savings for all projects in group
+        let total_cost: f64 = group.iter().filter_map(|(share, p)| p.contract_cost.map(|c| share * c)).sum();   // sums up contract_cost for all projects in group
+
+        let reliability = ((1.0 - (avg_delay / 90.0)) * (total_savings / total_cost) * 100.0).clamp(0.0, 100.0);
+        // reliability index = (1 - (avg delay / 90)) * (total savings / total cost) * 100 (capped at 100)
+
+        let risk_flag = if reliability < 50.0 { 
+            "High Risk" 
+        } else { 
+            "OK" 
+        };
+
+        Row
+        {
+            contractor,
+            num_projects: group.len(),
+            reliability_index: round2(reliability),
+            risk_flag: risk_flag.to_string(),
+            total_cost: round2(total_cost),
+            avg_delay: round2(avg_delay),
+            total_savings: round2(total_savings),
+        }
+    }).collect();
+
+    // sort & truncate
+    // Sort descending by total_cost
+    rows.sort_by(|a, b| b.total_cost.partial_cmp(&a.total_cost).unwrap_or(Ordering::Equal).then_with(|| a.contractor.cmp(&b.contractor)));  // ties (members of one joint venture) by name
+    rows.truncate(15);  // keep only top 15 contractors by total_cost
+
+    let jv = format!("joint ventures: {}", config.jv_attribution.name());
+    ReportResult::with_settings("Top Contractors Performance Ranking", config, &[jv], rows)
+}
+
+impl TableRow for Row
+{
+    const COLUMNS: &'static [&'static str] =
+        &["Contractor", "NumProjects", "TotalCost", "AvgDelay", "TotalSavings", "ReliabilityIndex", "RiskFlag"];
+
+    fn record(&self) -> Vec<String>
+    {
+        vec![
+            self.contractor.clone(),
+            self.num_projects.to_string(),
+            format!("{:.2}", self.total_cost),
+            format!("{:.2}", self.avg_delay),
+            format!("{:.2}", self.total_savings),
+            format!("{:.2}", self.reliability_index),
+            self.risk_flag.clone(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn joint_venture() -> Project
+    {
+        let mut p = Project::new();
+        p.contractor_entity = Some("ALPHA / BETA JV".to_string());
+        p.contractor_members = vec!["ALPHA".to_string(), "BETA".to_string()];
+        p
+    }
+
+    #[test]
+    fn joint_ventures_are_credited_per_mode()
+    {
+        let p = joint_venture();
+        assert_eq!(credits(&p, JvAttribution::Entity), [("ALPHA / BETA JV".to_string(), 1.0)]);
+        assert_eq!(credits(&p, JvAttribution::Full), [("ALPHA".to_string(), 1.0), ("BETA".to_string(), 1.0)]);
+        assert_eq!(credits(&p, JvAttribution::Share), [("ALPHA".to_string(), 0.5), ("BETA".to_string(), 0.5)]);
+        assert_eq!(credits(&Project::new(), JvAttribution::Share), [("Unknown".to_string(), 1.0)]);
+    }
+
+    #[test]
+    fn shared_projects_count_once_per_member()
+    {
+        let projects: Vec<Project> = (0..5).map(|_| {
+            let mut p = joint_venture();
+            p.contract_cost = Some(100.0);
+            p.cost_savings = Some(10.0);
+            p
+        }).collect();
+        let mut config = RunConfig::new();
+        config.jv_attribution = JvAttribution::Share;
+        let result = top_contractors(&projects, &config);
+
+        assert!(result.settings.ends_with("; joint ventures: share)"));
+        let alpha = result.rows.iter().find(|r| r.contractor == "ALPHA").unwrap();
+        assert_eq!((alpha.num_projects, alpha.total_cost, alpha.total_savings), (5, 250.0, 25.0));
+    }
+}
@@ -0,0 +1,165 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::controller::group::par_group_by;
+use crate::controller::tabular::{ReportResult, TableRow};
+use crate::model::{Project, round2};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Row {
+    pub year: i32,
+    pub type_of_work: String,
+    pub total_projects: usize,
+    pub avg_savings: f64,
+    pub overrun_rate: f64,
+    pub yoy_change: f64,
+}
+
+/* Savings and overrun rate per FundingYear + TypeOfWork, with the year-over-year change in average savings */
+pub fn annual_trends(projects: &[Project], config: &RunConfig) -> ReportResult<Row>
+{
+    // ----------- Group by (FundingYear, TypeOfWork) -----------
+    let map = par_group_by(projects, |p| Some((p.funding_year?, p.type_of_work.clone()?)));
+
+    // ----------- Compute Rows (without YoY) -----------
+    let computed: Vec<(Row, f64)> = map
+        .into_par_iter()
+        .map(|((year, work), group)| {
+            // Only rows with CostSavings count; a parent-only member row is left out, not averaged as a zero
+            let savings: Vec<f64> = group
+                .iter()
+                .filter_map(|p| p.cost_savings)
+                .collect();
+
+            // Average savings for the group
+            let avg_savings = if savings.is_empty() {
+                0.0
+            }
+            else {
+                savings.iter().sum::<f64>() / savings.len() as f64
+            };
+
+            // Overrun rate = (# of projects where CostSavings < 0) / projects with CostSavings * 100
+            let overruns = savings.iter().filter(|&&s| s < 0.0).count();
+            let overrun_rate = if savings.is_empty() {
+                0.0
+            }
+            else {
+                (overruns as f64 / savings.len() as f64) * 100.0
+            };
+
+            let row = Row {
+                year,
+                type_of_work: work,
+                total_projects: group.len(),
+                avg_savings: round2(avg_savings),
+                overrun_rate: round2(overrun_rate),
+                yoy_change: 0.0, // fill later
+            };
+            (row, avg_savings)  // keep the unrounded avg_savings for YoY calculation later
+        })
+        .collect();
+
+    let avg_savings_map: HashMap<(i32, String), f64> =
+        computed.iter().map(|(r, avg)| ((r.year, r.type_of_work.clone()), *avg)).collect();
+    let mut rows: Vec<Row> = computed.into_iter().map(|(r, _)| r).collect();
+
+    // ----------- Compute YoY (% change from previous year) -----------
+    // YoY = ((current year avg - previous year avg) / previous year avg) * 100 if previous year avg != 0
+    for row in rows.iter_mut() {
+        let curr = row.avg_savings;
+
+        if let Some(prev) = avg_savings_map.get(&(row.year - 1, row.type_of_work.clone())) {
+            if *prev != 0.0 {
+                row.yoy_change = round2(((curr - prev) / prev) * 100.0);
+            } else {
+                row.yoy_change = 0.0;
+            }
+        } else {
+            row.yoy_change = 0.0; // baseline year (2021)
+        }
+    }
+
+    // Sort results by year ascending then avg_savings descending
+    rows.sort_by(|a, b| {
+        a.year
+            .cmp(&b.year)
+            .then(b.avg_savings.partial_cmp(&a.avg_savings).unwrap_or(std::cmp::Ordering::Equal))
+    });
+
+    ReportResult::new("Annual Project Type Cost Overrun Trends", config, rows)
+}
+
+impl TableRow for Row
+{
+    const COLUMNS: &'static [&'static str] =
+        &["FundingYear", "TypeOfWork", "TotalProjects", "AvgSavings", "OverrunRate", "YoYChange"];
+
+    fn record(&self) -> Vec<String>
+    {
+        vec![
+            self.year.to_string(),
+            self.type_of_work.clone(),
+            self.total_projects.to_string(),
+            format!("{:.2}", self.avg_savings),
+            format!("{:.2}", self.overrun_rate),
+            format!("{:.2}", self.yoy_change),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn project(year: i32, work: &str, savings: f64) -> Project
+    {
+        let mut p = Project::new();
+        p.funding_year = Some(year);
+        p.type_of_work = Some(work.to_string());
+        p.cost_savings = Some(savings);
+        p
+    }
+
+    #[test]
+    fn trends_are_computed_without_printing()
+    {
+        let projects = [
+            project(2021, "Drainage", 100.0),
+            project(2021, "Drainage", -50.0),
+            project(2022, "Drainage", 50.0),
+            project(2022, "Dike", 10.0),
+        ];
+        let result = annual_trends(&projects, &RunConfig::new());
+        let rows: Vec<(i32, &str, usize, f64, f64, f64)> = result.rows.iter()
+            .map(|r| (r.year, r.type_of_work.as_str(), r.total_projects, r.avg_savings, r.overrun_rate, r.yoy_change))
+            .collect();
+        assert_eq!(rows, [
+            (2021, "Drainage", 2, 25.0, 50.0, 0.0),
+            (2022, "Drainage", 1, 50.0, 0.0, 100.0),
+            (2022, "Dike", 1, 10.0, 0.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    fn parent_only_members_are_left_out_of_averages()
+    {
+        // the member's amount is counted on its cluster parent, so it has no CostSavings of its own
+        let mut member = project(2022, "Drainage", 0.0);
+        member.cost_savings = None;
+        let projects = [project(2022, "Drainage", 100.0), member, project(2023, "Drainage", -20.0)];
+        let result = annual_trends(&projects, &RunConfig::new());
+        let rows: Vec<(i32, usize, f64, f64)> = result.rows.iter()
+            .map(|r| (r.year, r.total_projects, r.avg_savings, r.overrun_rate))
+            .collect();
+        assert_eq!(rows, [(2022, 2, 100.0, 0.0), (2023, 1, -20.0, 100.0)]);
+    }
+}
@@ -0,0 +1,259 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::model::{Hop, RefKind, Resolution, parse_float};
+use regex::Regex;   // for matching cluster/MYCA references
+use std::collections::{HashMap, HashSet};
+
+/* What one raw ApprovedBudgetForContract / ContractCost cell holds */
+#[derive(Debug, Clone)]
+pub enum MoneyCell
+{
+    Amount(f64),
+    Reference(Hop),
+    Empty,
+    Invalid,
+}
+
+/* Recognises "Clustered with Contract ID X" and "MYCA with Project ID Y" in money cells */
+pub struct RefParser
+{
+    cluster_re: Regex,
+    myca_re: Regex,
+}
+
+impl RefParser
+{
+    pub fn new() -> Self
+    {
+        Self
+        {
+            cluster_re: Regex::new(r"Clustered with Contract ID\s+([\w\-.]+)").unwrap(),    // regex to match "Clustered with Contract ID <ID>"
+            myca_re: Regex::new(r"MYCA with Project ID\s+([\w\-.]+)").unwrap(),     // regex to match "MYCA with Project ID <ID>"
+        }
+    }
+
+    pub fn classify(&self, raw: &str) -> MoneyCell
+    {
+        if raw.trim().is_empty() {
+            return MoneyCell::Empty;
+        }
+        if let Some(amount) = parse_float(raw) {
+            return MoneyCell::Amount(amount);
+        }
+        if let Some(cap) = self.cluster_re.captures(raw) {
+            return MoneyCell::Reference(Hop { kind: RefKind::Cluster, id: cap[1].to_string() });
+        }
+        if let Some(cap) = self.myca_re.captures(raw) {
+            return MoneyCell::Reference(Hop { kind: RefKind::Myca, id: cap[1].to_string() });
+        }
+        MoneyCell::Invalid
+    }
+}
+
+impl Default for RefParser
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/* One money column of every row, indexed by ContractId (cluster targets) and ProjectId (MYCA targets) */
+pub struct ColumnResolver
+{
+    cells: Vec<MoneyCell>,
+    by_contract: HashMap<String, usize>,
+    by_project: HashMap<String, usize>,
+}
+
+impl ColumnResolver
+{
+    pub fn new() -> Self
+    {
+        Self
+        {
+            cells: Vec::new(),
+            by_contract: HashMap::new(),
+            by_project: HashMap::new(),
+        }
+    }
+
+    /* Adds the next row; rows must be pushed in the same order they are later resolved */
+    pub fn push(&mut self, contract_id: &str, project_id: &str, cell: MoneyCell)
+    {
+        let row = self.cells.len();
+        let has_amount = matches!(cell, MoneyCell::Amount(_));
+        for (id, index) in [(contract_id, &mut self.by_contract), (project_id, &mut self.by_project)] {
+            if id.is_empty() {
+                continue;
+            }
+            // a row with an amount always wins; otherwise keep the first row seen so chains can still pass through it
+            if has_amount {
+                index.insert(id.to_string(), row);
+            } else {
+                index.entry(id.to_string()).or_insert(row);
+            }
+        }
+        self.cells.push(cell);
+    }
+
+    fn target(&self, hop: &Hop) -> Option<usize>
+    {
+        match hop.kind {
+            RefKind::Cluster => self.by_contract.get(&hop.id).copied(),
+            RefKind::Myca => self.by_project.get(&hop.id).copied(),
+        }
+    }
+
+    /* Number of rows pushed so far */
+    pub fn len(&self) -> usize
+    {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.cells.is_empty()
+    }
+
+    /* Amount written in the row itself, ignoring references */
+    pub fn amount_at(&self, row: usize) -> Option<f64>
+    {
+        match self.cells[row] {
+            MoneyCell::Amount(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /* Follows the row's reference chain, returning the row that holds the amount (if any) and how it was reached */
+    pub fn follow(&self, row: usize) -> (Option<usize>, Resolution)
+    {
+        let mut hop = match &self.cells[row] {
+            MoneyCell::Amount(_) => return (Some(row), Resolution::Direct),
+            MoneyCell::Empty | MoneyCell::Invalid => return (None, Resolution::Missing),
+            MoneyCell::Reference(h) => h.clone(),
+        };
+
+        let mut visited: HashSet<usize> = HashSet::from([row]);
+        let mut path: Vec<Hop> = Vec::new();
+
+        loop {
+            path.push(hop.clone());
+            let Some(target) = self.target(&hop) else {
+                return (None, Resolution::Dangling(path));
+            };
+            if !visited.insert(target) {
+                return (None, Resolution::Cycle(path));
+            }
+            match &self.cells[target] {
+                MoneyCell::Amount(_) => return (Some(target), Resolution::Referenced(path)),
+                MoneyCell::Reference(next) => hop = next.clone(),
+                MoneyCell::Empty | MoneyCell::Invalid => return (None, Resolution::NoValue(path)),
+            }
+        }
+    }
+}
+
+impl Default for ColumnResolver
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    // (ContractId, ProjectId, raw money cell) per row
+    fn resolver(rows: &[(&str, &str, &str)]) -> ColumnResolver
+    {
+        let parser = RefParser::new();
+        let mut column = ColumnResolver::new();
+        for (contract, project, raw) in rows {
+            column.push(contract, project, parser.classify(raw));
+        }
+        column
+    }
+
+    fn ids(resolution: &Resolution) -> Vec<&str>
+    {
+        resolution.path().iter().map(|h| h.id.as_str()).collect()
+    }
+
+    #[test]
+    fn cells_are_classified()
+    {
+        let parser = RefParser::new();
+        assert!(matches!(parser.classify("1,234.50"), MoneyCell::Amount(v) if v == 1234.5));
+        assert!(matches!(parser.classify("Clustered with Contract ID C-01"), MoneyCell::Reference(Hop { kind: RefKind::Cluster, .. })));
+        assert!(matches!(parser.classify("MYCA with Project ID P0001"), MoneyCell::Reference(Hop { kind: RefKind::Myca, .. })));
+        assert!(matches!(parser.classify("  "), MoneyCell::Empty));
+        assert!(matches!(parser.classify("n/a"), MoneyCell::Invalid));
+    }
+
+    #[test]
+    fn chains_are_followed_to_the_amount()
+    {
+        let column = resolver(&[
+            ("C1", "P1", "100"),
+            ("C2", "P2", "Clustered with Contract ID C1"),
+            ("C3", "P3", "MYCA with Project ID P2"),
+        ]);
+        assert!(matches!(column.follow(0), (Some(0), Resolution::Direct)));
+        let (root, resolution) = column.follow(2);
+        assert_eq!(root, Some(0));
+        assert!(matches!(resolution, Resolution::Referenced(_)));
+        assert_eq!(ids(&resolution), ["P2", "C1"]);
+    }
+
+    #[test]
+    fn cycles_are_detected()
+    {
+        let column = resolver(&[
+            ("C1", "P1", "Clustered with Contract ID C2"),
+            ("C2", "P2", "Clustered with Contract ID C3"),
+            ("C3", "P3", "Clustered with Contract ID C1"),
+            ("C4", "P4", "Clustered with Contract ID C4"),
+        ]);
+        let (root, resolution) = column.follow(0);
+        assert_eq!(root, None);
+        assert!(matches!(resolution, Resolution::Cycle(_)));
+        assert_eq!(ids(&resolution), ["C2", "C3", "C1"]);
+        assert!(matches!(column.follow(3), (None, Resolution::Cycle(_))));   // refers to itself
+    }
+
+    #[test]
+    fn broken_chains_say_why()
+    {
+        let column = resolver(&[
+            ("C1", "P1", ""),
+            ("C2", "P2", "Clustered with Contract ID C1"),
+            ("C3", "P3", "Clustered with Contract ID NOPE"),
+            ("C4", "P4", "MYCA with Project ID P3"),
+        ]);
+        assert!(matches!(column.follow(0), (None, Resolution::Missing)));
+        assert!(matches!(column.follow(1), (None, Resolution::NoValue(_))));
+        assert!(matches!(column.follow(2), (None, Resolution::Dangling(_))));
+        let (_, resolution) = column.follow(3);
+        assert!(matches!(resolution, Resolution::Dangling(_)));
+        assert_eq!(ids(&resolution), ["P3", "NOPE"]);
+    }
+
+    #[test]
+    fn a_row_with_an_amount_wins_a_shared_id()
+    {
+        let column = resolver(&[
+            ("C1", "P1", "Clustered with Contract ID C9"),
+            ("C1", "P2", "250"),
+            ("C3", "P3", "Clustered with Contract ID C1"),
+        ]);
+        assert_eq!(column.follow(2).0, Some(1));
+    }
+}
@@ -0,0 +1,160 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use csv::StringRecord;
+use std::collections::HashMap;
+use std::error::Error;
+
+/* How the loader parses a column */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType
+{
+    Text,
+    Integer,
+    Decimal,
+    Date,
+    Money,  // amount, or a cluster/MYCA reference to another row's amount
+}
+
+impl ColumnType
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            ColumnType::Text => "text",
+            ColumnType::Integer => "integer",
+            ColumnType::Decimal => "decimal",
+            ColumnType::Date => "date",
+            ColumnType::Money => "amount or reference",
+        }
+    }
+}
+
+/* One input column: canonical name, type, whether loading can proceed without it, and other accepted headers */
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnSpec
+{
+    pub name: &'static str,
+    pub kind: ColumnType,
+    pub required: bool,
+    pub aliases: &'static [&'static str],
+}
+
+const fn column(name: &'static str, kind: ColumnType, required: bool, aliases: &'static [&'static str]) -> ColumnSpec
+{
+    ColumnSpec { name, kind, required, aliases }
+}
+
+// Every column the loader reads. Headers match ignoring case, spaces and punctuation,
+// so "Contract ID" and "contract_id" already match ContractId; aliases cover real renames.
+pub const SCHEMA: [ColumnSpec; 15] = [
+    column("ProjectId", ColumnType::Text, true, &["ProjectCode"]),
+    column("ContractId", ColumnType::Text, true, &["ContractNo", "ContractNumber"]),
+    column("FundingYear", ColumnType::Integer, true, &["Year", "InfraYear"]),
+    column("Region", ColumnType::Text, true, &[]),
+    column("MainIsland", ColumnType::Text, true, &["Island", "IslandGroup"]),
+    column("Province", ColumnType::Text, true, &[]),
+    column("Contractor", ColumnType::Text, true, &["ContractorName"]),
+    column("TypeOfWork", ColumnType::Text, true, &["WorkType"]),
+    column("ApprovedBudgetForContract", ColumnType::Money, true, &["ABC", "ApprovedBudget"]),
+    column("ContractCost", ColumnType::Money, true, &["ContractAmount"]),
+    column("StartDate", ColumnType::Date, true, &["ActualStartDate", "DateStarted"]),
+    column("ActualCompletionDate", ColumnType::Date, true, &["CompletionDate", "DateCompleted"]),
+    // the original completion date is published under either name, and only in some extracts
+    column("TargetCompletionDate", ColumnType::Date, false, &["OriginalCompletionDate"]),
+    column("ProjectLatitude", ColumnType::Decimal, false, &["Latitude", "Lat"]),
+    column("ProjectLongitude", ColumnType::Decimal, false, &["Longitude", "Lon", "Lng"]),
+];
+
+// "Contract ID", "contract_id" and "\u{feff}ContractId" (BOM) all become "contractid"
+fn normalize(header: &str) -> String
+{
+    header.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/* Key under which headers of different files are merged: the schema column a header names
+   (so "ContractAmount" and "ContractCost" share one column), otherwise the normalized header */
+pub fn column_key(header: &str) -> String
+{
+    let key = normalize(header);
+    SCHEMA.iter()
+        .find(|spec| std::iter::once(spec.name).chain(spec.aliases.iter().copied()).any(|n| normalize(n) == key))
+        .map_or(key, |spec| normalize(spec.name))
+}
+
+/* Header position of every schema column present in the file, keyed by canonical name.
+   Fails, naming every missing required column, before any row is read. */
+pub fn match_headers(headers: &StringRecord) -> Result<HashMap<&'static str, usize>, Box<dyn Error>>
+{
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for (i, h) in headers.iter().enumerate() {
+        positions.entry(normalize(h)).or_insert(i); // the first of duplicate headers wins
+    }
+
+    let mut found: HashMap<&'static str, usize> = HashMap::new();
+    let mut missing: Vec<String> = Vec::new();
+    for spec in &SCHEMA {
+        let index = std::iter::once(spec.name).chain(spec.aliases.iter().copied()).find_map(|n| positions.get(&normalize(n)).copied());
+        match index {
+            Some(i) => {
+                found.insert(spec.name, i);
+            }
+            None if spec.required => {
+                let accepted = if spec.aliases.is_empty() { String::new() } else { format!(", also accepted: {}", spec.aliases.join(", ")) };
+                missing.push(format!("{} ({}{accepted})", spec.name, spec.kind.name()));
+            }
+            None => {}
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "input is missing {} required column(s): {}\nheaders found: {}",
+            missing.len(),
+            missing.join("; "),
+            headers.iter().collect::<Vec<&str>>().join(", ")
+        )
+        .into());
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    // Every required column, under its canonical name unless `replace` gives another header
+    fn headers(replace: &[(&str, &str)]) -> StringRecord
+    {
+        SCHEMA.iter()
+            .filter(|spec| spec.required)
+            .map(|spec| replace.iter().find(|(from, _)| *from == spec.name).map_or(spec.name, |(_, to)| *to))
+            .collect()
+    }
+
+    #[test]
+    fn aliases_and_spelling_variants_match_the_canonical_column()
+    {
+        let found = match_headers(&headers(&[("ContractCost", "Contract Amount"), ("ProjectId", "\u{feff}project_id")])).unwrap();
+        assert_eq!(found["ContractCost"], 9);
+        assert_eq!(found["ProjectId"], 0);
+        assert!(!found.contains_key("TargetCompletionDate"));   // optional, absent
+
+        assert_eq!(column_key("Contract Amount"), column_key("ContractCost"));
+        assert_eq!(column_key("Original Completion Date"), "targetcompletiondate");
+        assert_eq!(column_key("Remarks"), "remarks");   // not in the schema: kept as its own column
+    }
+
+    #[test]
+    fn a_missing_required_column_is_named()
+    {
+        let mut record: Vec<String> = headers(&[]).iter().map(str::to_string).collect();
+        record.retain(|h| h != "ContractCost");
+        let error = match_headers(&StringRecord::from(record)).unwrap_err().to_string();
+        assert!(error.starts_with("input is missing 1 required column(s): ContractCost (amount or reference, also accepted: ContractAmount)"), "{error}");
+    }
+}
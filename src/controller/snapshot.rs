@@ -0,0 +1,109 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::controller::loader::{LOADER_VERSION, Loaded};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};   // hash of the source file
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+// First bytes of every snapshot file, so unrelated files are never decoded
+const MAGIC: &[u8; 8] = b"DPWHSNAP";
+
+/* Everything a snapshot depends on; a snapshot is reused only if its key matches exactly */
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotKey
+{
+    loader_version: u32,
+    source_hash: String,    // SHA-256 of each raw input file, in load order
+    settings: String,       // load-time options that change the cleaned data or the data-quality findings
+}
+
+impl SnapshotKey
+{
+    /* Hashes the input files and records the settings of this run */
+    pub fn for_run(files: &[PathBuf], config: &RunConfig) -> Result<Self, Box<dyn Error>>
+    {
+        // a reviewed alias table changes the contractor entities, so it is part of the source
+        let hashes = files.iter().chain(&config.contractor_aliases).map(|f| hash_file(f)).collect::<Result<Vec<String>, _>>()?;
+        Ok(Self
+        {
+            loader_version: LOADER_VERSION,
+            source_hash: hashes.join(","),
+            settings: format!(
+                "{}; sheet {}; header row {}; aliases {}; {}; {}; {}; {}",
+                files.iter().map(|f| f.display().to_string()).collect::<Vec<String>>().join(", "),
+                config.sheet.as_deref().unwrap_or("first"),
+                config.header_row,
+                config.contractor_aliases.as_ref().map_or("automatic".to_string(), |p| p.display().to_string()),
+                config.window.describe(),
+                config.allocation.name(),
+                config.delay_basis.name(),
+                config.delay_bounds.describe(config.delay_basis)
+            ),
+        })
+    }
+}
+
+// Streams the file through SHA-256 without reading it into memory
+fn hash_file(path: &Path) -> Result<String, Box<dyn Error>>
+{
+    let mut reader = BufReader::new(File::open(path).map_err(|e| format!("{}: {e}", path.display()))?);
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let n = reader.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/* Snapshot file for the inputs: <input file stem>.snapshot in the output directory, merged.snapshot for several files */
+pub fn snapshot_path(files: &[PathBuf], config: &RunConfig) -> PathBuf
+{
+    let stem = match files {
+        [file] => file.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_else(|| "dataset".to_string()),
+        _ => "merged".to_string(),
+    };
+    config.output_path(&format!("{stem}.snapshot"))
+}
+
+/* Loads a snapshot if it exists and was written for `key`; any mismatch or decoding error means "rebuild" */
+pub fn read_snapshot(path: &Path, key: &SnapshotKey) -> Option<Loaded>
+{
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic).ok()?;
+    if &magic != MAGIC {
+        return None;
+    }
+    // the key is checked before the payload, so a snapshot from another loader version is never decoded
+    let stored: SnapshotKey = bincode::deserialize_from(&mut reader).ok()?;
+    if stored != *key {
+        return None;
+    }
+    bincode::deserialize_from(&mut reader).ok()
+}
+
+/* Writes the snapshot through a temporary file so an interrupted run never leaves a truncated snapshot behind */
+pub fn write_snapshot(path: &Path, key: &SnapshotKey, loaded: &Loaded) -> Result<(), Box<dyn Error>>
+{
+    let tmp_path = path.with_extension("snapshot.tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path)?);
+    writer.write_all(MAGIC)?;
+    bincode::serialize_into(&mut writer, key)?;
+    bincode::serialize_into(&mut writer, loaded)?;
+    writer.flush()?;
+    drop(writer);
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
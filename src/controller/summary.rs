@@ -0,0 +1,81 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::model::Project;
+use rayon::prelude::*;  // builds the unique sets in parallel
+use serde::Serialize;   // for converting structs to JSON
+use std::collections::HashSet;
+
+// Structure for summary.json
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryJson {
+    pub analysis_window: String,
+    pub allocation_policy: String,
+    pub delay_bounds: String,
+    pub delay_basis: String,
+    pub total_projects: usize,
+    pub total_contractors: usize,
+    pub total_provinces: usize,
+    pub global_avg_delay_days: f64,
+    pub global_total_savings: f64,
+}
+
+// Cleans province name by trimming and uppercasing
+fn clean_province(name: &str) -> Option<String> {
+    let n = name.trim();
+    if n.is_empty() { 
+        return None; 
+    }
+    Some(n.to_uppercase())
+}
+
+// Key statistics across every project
+pub fn summary(projects: &[Project], config: &RunConfig) -> SummaryJson {
+    let total_projects = projects.len();
+
+    // Unique Contractors (resolved entities; empty names and cluster/MYCA references have none)
+    let contractor_set: HashSet<&str> = projects
+        .par_iter()
+        .filter_map(|p| p.contractor_entity.as_deref())
+        .collect();
+
+    // Unique Provinces
+    let province_set: HashSet<String> = projects
+        .par_iter()
+        .filter_map(|p| p.province.as_deref().and_then(clean_province))   // if province exists, clean the name
+        .collect();
+
+    // Global Average Delay Days
+    let delays: Vec<f64> = projects
+        .iter()
+        .filter_map(|p| p.delay_days(config.delay_basis).map(|d| d as f64))
+        .collect();
+
+    let global_avg_delay_days = if delays.is_empty() {
+        0.0
+    } else {
+        delays.iter().sum::<f64>() / delays.len() as f64    //  average calculation
+    };
+
+    // Total Savings
+    let global_total_savings = projects
+        .iter()
+        .filter_map(|p| p.cost_savings)
+        .sum::<f64>();
+
+    SummaryJson {
+        analysis_window: config.window.describe(),
+        allocation_policy: config.allocation.name().to_string(),
+        delay_bounds: config.delay_bounds.describe(config.delay_basis),
+        delay_basis: config.delay_basis.name().to_string(),
+        total_projects,
+        total_contractors: contractor_set.len(),
+        total_provinces: province_set.len(),
+        global_avg_delay_days,
+        global_total_savings,
+    }
+}
@@ -0,0 +1,46 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use serde::Serialize;
+
+/* Computed result of one report, independent of how it is rendered (terminal table, CSV, JSON) */
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportResult<R>
+{
+    pub title: String,
+    pub settings: String,   // filters and policies the rows were computed with (RunConfig::header_with)
+    pub rows: Vec<R>,
+}
+
+impl<R> ReportResult<R>
+{
+    pub fn new(title: &str, config: &RunConfig, rows: Vec<R>) -> Self
+    {
+        Self::with_settings(title, config, &[], rows)
+    }
+
+    /* A result whose settings add `extra` (settings only this report uses) to the shared ones */
+    pub fn with_settings(title: &str, config: &RunConfig, extra: &[String], rows: Vec<R>) -> Self
+    {
+        Self
+        {
+            title: title.to_string(),
+            settings: config.header_with(extra),
+            rows,
+        }
+    }
+}
+
+/* A report row that can be written as one line of a flat table (CSV) */
+pub trait TableRow
+{
+    // Column headers, in the order of record()
+    const COLUMNS: &'static [&'static str];
+
+    // Field values as written to the CSV (amounts rounded to 2 decimals, no thousands separators)
+    fn record(&self) -> Vec<String>;
+}
@@ -0,0 +1,393 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::controller::geography::LocationCheck;
+use crate::model::{CoordIssue, DateIssue, ParsedDate, Resolution};
+use serde::{Deserialize, Serialize};   // for exporting the report as JSON (and storing it in the snapshot)
+use std::collections::BTreeSet;   // sorted, de-duplicated ID lists
+
+// Raw values kept per column/field so the export stays small on large datasets
+pub const MAX_SAMPLES: usize = 5;
+
+/* One offending value and the data row it came from (1-based within its file, header excluded) */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sample
+{
+    pub file: String,
+    pub row: u64,
+    pub value: String,
+}
+
+/* Problems found in one input column */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnQuality
+{
+    pub column: String,
+    pub empty: usize,
+    pub unparseable: usize,
+    pub unresolved: usize,  // cluster/MYCA references that could not be followed
+    pub samples: Vec<Sample>,
+}
+
+/* Rows where a derived field (CostSavings, CompletionDelayDays) could not be computed or was implausible */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DerivedQuality
+{
+    pub field: String,
+    pub failures: usize,
+    pub implausible: usize,     // computed but outside the configured bounds
+    pub samples: Vec<Sample>,  // value holds the reason
+}
+
+/* Outcome of cluster/MYCA reference resolution, counted per money cell */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReferenceSummary
+{
+    pub resolved: usize,
+    pub multi_hop: usize,   // resolved through more than one reference
+    pub dangling: usize,
+    pub no_value: usize,
+    pub cycles: usize,
+    pub dangling_ids: BTreeSet<String>,
+    pub cycle_paths: BTreeSet<String>,
+}
+
+/* Inferred format of one date column and the values that did not fit it cleanly */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DateColumnSummary
+{
+    pub column: String,
+    pub file: String,   // formats are inferred per input file
+    pub dominant_format: Option<String>,
+    pub ambiguous: usize,
+    pub inconsistent: usize,
+    pub samples: Vec<Sample>,
+}
+
+/* Province/Region/MainIsland values checked against the reference table (controller::geography) */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocationSummary
+{
+    pub normalized: usize,      // values rewritten to the reference spelling
+    pub filled: usize,          // empty Region/MainIsland filled from the province or region
+    pub unknown: usize,         // values not in the reference table (kept as they are)
+    pub inconsistent: usize,    // rows whose province, region and island contradict each other
+    pub unknown_values: BTreeSet<String>,
+    pub samples: Vec<Sample>,   // value describes the contradiction
+}
+
+/* One flagged lat/lon pair: the issue found, and the row with its raw coordinates and the reason as value */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaggedPoint
+{
+    pub issue: CoordIssue,
+    pub sample: Sample,
+}
+
+/* Project coordinates that failed the geographic check, every one of them listed */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoordinateSummary
+{
+    pub zero: usize,
+    pub swapped: usize,
+    pub outside_country: usize,
+    pub far_from_province: usize,
+    pub flagged: Vec<FlaggedPoint>,   // in row order
+}
+
+impl CoordinateSummary
+{
+    pub fn total(&self) -> usize
+    {
+        self.zero + self.swapped + self.outside_country + self.far_from_province
+    }
+}
+
+/* Data-quality findings of one load (REQ-0002) */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationReport
+{
+    pub source: String,
+    pub files: Vec<String>,     // every input file, in load order
+    pub total_rows: usize,
+    pub columns: Vec<ColumnQuality>,
+    pub derived: Vec<DerivedQuality>,
+    pub references: ReferenceSummary,
+    pub dates: Vec<DateColumnSummary>,
+    pub locations: LocationSummary,
+    pub coordinates: CoordinateSummary,
+}
+
+impl ValidationReport
+{
+    pub fn new(source: &str) -> Self
+    {
+        Self
+        {
+            source: source.to_string(),
+            files: Vec::new(),
+            total_rows: 0,
+            columns: Vec::new(),
+            derived: Vec::new(),
+            references: ReferenceSummary::default(),
+            dates: Vec::new(),
+            locations: LocationSummary::default(),
+            coordinates: CoordinateSummary::default(),
+        }
+    }
+
+    // Finds the entry for a column, adding it the first time it is seen (keeps columns in check order)
+    fn column_mut(&mut self, column: &str) -> &mut ColumnQuality
+    {
+        let index = match self.columns.iter().position(|c| c.column == column) {
+            Some(i) => i,
+            None => {
+                self.columns.push(ColumnQuality
+                {
+                    column: column.to_string(),
+                    empty: 0,
+                    unparseable: 0,
+                    unresolved: 0,
+                    samples: Vec::new(),
+                });
+                self.columns.len() - 1
+            }
+        };
+        &mut self.columns[index]
+    }
+
+    fn derived_mut(&mut self, field: &str) -> &mut DerivedQuality
+    {
+        let index = match self.derived.iter().position(|d| d.field == field) {
+            Some(i) => i,
+            None => {
+                self.derived.push(DerivedQuality
+                {
+                    field: field.to_string(),
+                    failures: 0,
+                    implausible: 0,
+                    samples: Vec::new(),
+                });
+                self.derived.len() - 1
+            }
+        };
+        &mut self.derived[index]
+    }
+
+    /* Registers a column as checked even if it turns out clean, so it still appears in the output */
+    pub fn track(&mut self, column: &str)
+    {
+        self.column_mut(column);
+    }
+
+    pub fn record_empty(&mut self, column: &str)
+    {
+        self.column_mut(column).empty += 1;
+    }
+
+    pub fn record_unparseable(&mut self, column: &str, row: u64, value: &str)
+    {
+        let c = self.column_mut(column);
+        c.unparseable += 1;
+        push_sample(&mut c.samples, row, value);
+    }
+
+    pub fn record_unresolved(&mut self, column: &str, row: u64, value: &str)
+    {
+        let c = self.column_mut(column);
+        c.unresolved += 1;
+        push_sample(&mut c.samples, row, value);
+    }
+
+    pub fn record_derived_failure(&mut self, field: &str, row: u64, reason: &str)
+    {
+        let d = self.derived_mut(field);
+        d.failures += 1;
+        push_sample(&mut d.samples, row, reason);
+    }
+
+    pub fn record_implausible(&mut self, field: &str, row: u64, reason: &str)
+    {
+        let d = self.derived_mut(field);
+        d.implausible += 1;
+        push_sample(&mut d.samples, row, reason);
+    }
+
+    /* Counts how a money cell's reference resolved, remembering dangling IDs and cycles */
+    pub fn record_resolution(&mut self, resolution: &Resolution)
+    {
+        let refs = &mut self.references;
+        match resolution {
+            Resolution::Referenced(path) => {
+                refs.resolved += 1;
+                if path.len() > 1 {
+                    refs.multi_hop += 1;
+                }
+            }
+            Resolution::Dangling(path) => {
+                refs.dangling += 1;
+                if let Some(last) = path.last() {
+                    refs.dangling_ids.insert(format!("{} {}", last.kind.name(), last.id));
+                }
+            }
+            Resolution::NoValue(_) => refs.no_value += 1,
+            Resolution::Cycle(_) => {
+                refs.cycles += 1;
+                refs.cycle_paths.insert(resolution.describe_path());
+            }
+            Resolution::Direct | Resolution::Missing => {}
+        }
+    }
+
+    /* Counts the outcome of normalizing one row's location fields */
+    pub fn record_location(&mut self, row: u64, check: &LocationCheck)
+    {
+        let l = &mut self.locations;
+        l.normalized += check.normalized;
+        l.filled += check.filled;
+        l.unknown += check.unknown.len();
+        l.unknown_values.extend(check.unknown.iter().cloned());
+        if !check.conflicts.is_empty() {
+            l.inconsistent += 1;
+            push_sample(&mut l.samples, row, &check.conflicts.join("; "));
+        }
+    }
+
+    /* Counts a flagged lat/lon pair and lists its row */
+    pub fn record_coordinates(&mut self, row: u64, issue: CoordIssue, detail: &str)
+    {
+        let c = &mut self.coordinates;
+        match issue {
+            CoordIssue::Zero => c.zero += 1,
+            CoordIssue::Swapped => c.swapped += 1,
+            CoordIssue::OutsideCountry => c.outside_country += 1,
+            CoordIssue::FarFromProvince { .. } => c.far_from_province += 1,
+        }
+        let index = c.flagged.partition_point(|f| f.sample.row <= row);
+        c.flagged.insert(index, FlaggedPoint { issue, sample: Sample { file: String::new(), row, value: detail.to_string() } });
+    }
+
+    /* Registers the format inferred for a date column of one input file */
+    pub fn set_date_format(&mut self, column: &str, file: &str, format: Option<&str>)
+    {
+        self.dates.push(DateColumnSummary
+        {
+            column: column.to_string(),
+            file: file.to_string(),
+            dominant_format: format.map(str::to_string),
+            ambiguous: 0,
+            inconsistent: 0,
+            samples: Vec::new(),
+        });
+    }
+
+    /* Records an ambiguous or off-format date of a column registered with set_date_format
+       (empty and unparseable dates are checked like any other column) */
+    pub fn record_date_issue(&mut self, column: &str, file: &str, row: u64, raw: &str, parsed: &ParsedDate)
+    {
+        let Some(issue) = parsed.issue() else {
+            return;
+        };
+        if let Some(d) = self.dates.iter_mut().find(|d| d.column == column && d.file == file) {
+            if parsed.ambiguous {
+                d.ambiguous += 1;
+            }
+            if parsed.inconsistent {
+                d.inconsistent += 1;
+            }
+            push_sample(&mut d.samples, row, &format!("{raw} ({})", issue.name()));
+        }
+    }
+
+    /* Adds the unambiguous dates of a column that only parsed with a format other than its dominant one:
+       how many there were and the first of them as (row, raw value) */
+    pub fn record_off_format(&mut self, column: &str, file: &str, count: usize, samples: &[(u64, String)])
+    {
+        if let Some(d) = self.dates.iter_mut().find(|d| d.column == column && d.file == file) {
+            d.inconsistent += count;
+            for (row, raw) in samples {
+                push_sample(&mut d.samples, *row, &format!("{raw} ({})", DateIssue::Inconsistent.name()));
+            }
+        }
+    }
+
+    /* Checks one raw value: empty, or non-empty but rejected by the parser */
+    pub fn check<T>(&mut self, column: &str, row: u64, raw: &str, parsed: &Option<T>)
+    {
+        if raw.is_empty() {
+            self.record_empty(column);
+        }
+        else if parsed.is_none() {
+            self.record_unparseable(column, row, raw);
+        }
+        else {
+            self.track(column);
+        }
+    }
+
+    /* Rows are numbered across all input files while loading; this turns every sample's row into
+       the file it came from and its row within that file. `locate` maps a global row to both. */
+    pub fn localize_rows(&mut self, locate: impl Fn(u64) -> (String, u64))
+    {
+        let samples = self.columns.iter_mut().flat_map(|c| c.samples.iter_mut())
+            .chain(self.derived.iter_mut().flat_map(|d| d.samples.iter_mut()))
+            .chain(self.dates.iter_mut().flat_map(|d| d.samples.iter_mut()))
+            .chain(self.locations.samples.iter_mut())
+            .chain(self.coordinates.flagged.iter_mut().map(|f| &mut f.sample));
+        for s in samples {
+            (s.file, s.row) = locate(s.row);
+        }
+    }
+
+    /* Total number of problems across every column and derived field */
+    pub fn issue_count(&self) -> usize
+    {
+        let column_issues: usize = self.columns.iter().map(|c| c.empty + c.unparseable + c.unresolved).sum();
+        let derived_issues: usize = self.derived.iter().map(|d| d.failures + d.implausible).sum();
+        column_issues + derived_issues + self.locations.unknown + self.locations.inconsistent + self.coordinates.total()
+    }
+}
+
+/* How much of the pipeline a rejected row is missing from */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectScope
+{
+    Dropped,    // removed before any report runs
+    Partial,    // kept, but left out of the metrics that need the missing derived field
+}
+
+impl RejectScope
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            RejectScope::Dropped => "dropped",
+            RejectScope::Partial => "excluded from some metrics",
+        }
+    }
+}
+
+/* Position of an excluded input row and the reasons, written to rejected_rows.csv. The raw fields are not kept:
+   the writer reads them back from the input file (see loader::read_rejected). */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectedRow
+{
+    pub source: String, // input file
+    pub row: u64,       // 1-based within the file, header excluded
+    pub scope: RejectScope,
+    pub reasons: Vec<String>,
+}
+
+// Keeps the earliest MAX_SAMPLES rows, whatever order the rows are checked in (deferred rows come last)
+fn push_sample(samples: &mut Vec<Sample>, row: u64, value: &str)
+{
+    if samples.len() == MAX_SAMPLES && samples.last().is_some_and(|s| s.row < row) {
+        return;
+    }
+    let index = samples.partition_point(|s| s.row <= row);
+    samples.insert(index, Sample { file: String::new(), row, value: value.to_string() });   // file is set by localize_rows
+    samples.truncate(MAX_SAMPLES);
+}
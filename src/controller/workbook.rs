@@ -0,0 +1,84 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use calamine::{Data, Reader, open_workbook_auto};   // reads .xlsx/.xlsm/.xlsb/.xls/.ods workbooks
+use csv::StringRecord;
+use std::error::Error;
+use std::path::Path;
+
+// Extensions read as a workbook instead of CSV
+pub const WORKBOOK_EXTENSIONS: [&str; 5] = ["xlsx", "xlsm", "xlsb", "xls", "ods"];
+
+/* True if the file should be opened as a workbook (decided by its extension) */
+pub fn is_workbook(path: &Path) -> bool
+{
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| WORKBOOK_EXTENSIONS.iter().any(|w| e.eq_ignore_ascii_case(w)))
+}
+
+/* Reads one sheet as text records, the way the CSV reader would see it.
+   `sheet` is a sheet name or a 1-based position (first sheet if None); `header_row` is the 1-based sheet row holding
+   the column names, so title rows above the table are skipped. Returns the header record and the data records. */
+pub fn read_sheet(path: &Path, sheet: Option<&str>, header_row: usize) -> Result<(StringRecord, Vec<StringRecord>), Box<dyn Error>>
+{
+    let above = header_row.checked_sub(1).ok_or("the header row is 1-based")?;
+    let mut workbook = open_workbook_auto(path)?;
+    let names = workbook.sheet_names();
+    let name = match sheet {
+        None => names.first().cloned().ok_or("workbook has no sheets")?,
+        Some(wanted) => find_sheet(&names, wanted).ok_or_else(|| format!("no sheet '{wanted}' in {} (sheets: {})", path.display(), names.join(", ")))?,
+    };
+    let range = workbook.worksheet_range(&name)?;
+
+    // the range starts at the first non-empty cell, so rows are counted from its absolute position
+    let first_row = range.start().map_or(0, |(row, _)| row as usize);
+    let skip = above.saturating_sub(first_row);
+    let mut rows = range.rows().skip(skip).map(|cells| cells.iter().map(cell_text).collect::<StringRecord>());
+
+    let headers = rows.next().ok_or_else(|| format!("sheet '{name}' has no row {header_row}"))?;
+    let records: Vec<StringRecord> = rows.filter(|r| r.iter().any(|f| !f.trim().is_empty())).collect();  // blank rows carry no project
+    Ok((headers, records))
+}
+
+// Sheet by exact name, case-insensitive name, or 1-based position
+fn find_sheet(names: &[String], wanted: &str) -> Option<String>
+{
+    let wanted = wanted.trim();
+    names.iter().find(|n| n.as_str() == wanted)
+        .or_else(|| names.iter().find(|n| n.eq_ignore_ascii_case(wanted)))
+        .or_else(|| wanted.parse::<usize>().ok().filter(|&i| i >= 1).and_then(|i| names.get(i - 1)))
+        .cloned()
+}
+
+/* Text of one cell. Dates become their Excel serial number, which model::try_parse_date understands */
+fn cell_text(cell: &Data) -> String
+{
+    match cell {
+        Data::Empty => String::new(),
+        Data::String(s) => s.clone(),
+        Data::Int(i) => i.to_string(),
+        Data::Float(f) => f.to_string(),
+        Data::Bool(b) => b.to_string(),
+        Data::DateTime(d) => d.as_f64().to_string(),
+        Data::DateTimeIso(s) => s.split('T').next().unwrap_or("").to_string(),  // date part of an ISO timestamp (.ods)
+        Data::DurationIso(s) => s.clone(),
+        Data::Error(e) => e.to_string(),    // "#N/A", "#REF!", ... kept so the value is reported as unparseable
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn a_header_row_of_zero_is_rejected_before_the_file_is_opened()
+    {
+        let read = read_sheet(Path::new("missing.xlsx"), None, 0);
+        assert_eq!(read.err().map(|e| e.to_string()).as_deref(), Some("the header row is 1-based"));
+    }
+}
@@ -0,0 +1,19 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+/* Library side of the pipeline, usable from other tools:
+   - model: Project and the parsing helpers
+   - config: RunConfig (input, analysis window, allocation and delay settings)
+   - controller: loading (controller::loader::load_path: CSV or workbook, one or several files, filtered by the analysis
+     window like the binary; load_files / load_records return every row, Loaded::apply_window filters them) and the report computations
+     (controller::report1::regional_efficiency, report2::top_contractors, report3::annual_trends, summary::summary),
+     which are pure functions returning typed rows
+   - view: console/CSV/JSON output, the interactive menu and the command line used by the binary */
+
+pub mod config;
+pub mod model;
+pub mod controller;
+pub mod view;
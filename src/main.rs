@@ -4,12 +4,11 @@ Language: JavaScript
 Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
 ********************/
 
-mod model;
-mod controller;
-mod view;
+use csadprg_mp2::view::cli;    // every module lives in the library (src/lib.rs)
+use std::process::ExitCode;
 
-fn main() -> Result<(), Box<dyn std::error::Error>>
+fn main() -> ExitCode
 {
-    view::menu::main_menu()?;
-    Ok(())
+    let args: Vec<String> = std::env::args().skip(1).collect();  // skips program name
+    cli::run(&args)
 }
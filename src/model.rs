@@ -4,9 +4,155 @@ Language: JavaScript
 Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
 ********************/
 
+use crate::config::DelayBasis;
 use chrono::NaiveDate; // imports NaiveDate (represents calendar date with no timezone)
+use serde::{Deserialize, Serialize};    // cleaned projects are persisted in the dataset snapshot
 
-#[derive(Debug, Clone)]
+/* Where a project's lat/lon came from, so consumers can skip imputed points */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoordSource
+{
+    Observed,           // both values present in the dataset
+    ImputedProvince,    // filled with the average of the project's province
+    ImputedRegion,      // province had no usable points, filled with the region average
+    Missing,            // nothing to impute from
+}
+
+/* Kind of reference a money cell can hold instead of an amount */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RefKind
+{
+    Cluster,    // "Clustered with Contract ID X" (looked up by ContractId)
+    Myca,       // "MYCA with Project ID Y" (looked up by ProjectId)
+}
+
+impl RefKind
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            RefKind::Cluster => "cluster",
+            RefKind::Myca => "MYCA",
+        }
+    }
+}
+
+/* One step of a reference chain */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hop
+{
+    pub kind: RefKind,
+    pub id: String,
+}
+
+/* How a project's budget or cost was obtained */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Resolution
+{
+    Direct,                 // amount written in the row itself
+    Referenced(Vec<Hop>),   // followed the chain to an amount (one hop per reference)
+    Dangling(Vec<Hop>),     // last hop names an ID that is not in the dataset
+    NoValue(Vec<Hop>),      // chain ends at a row without an amount
+    Cycle(Vec<Hop>),        // chain returns to a row already visited
+    Missing,                // empty or unparseable, not a reference
+}
+
+impl Resolution
+{
+    /* Chain followed, empty for Direct/Missing */
+    pub fn path(&self) -> &[Hop]
+    {
+        match self {
+            Resolution::Referenced(p) | Resolution::Dangling(p) | Resolution::NoValue(p) | Resolution::Cycle(p) => p,
+            Resolution::Direct | Resolution::Missing => &[],
+        }
+    }
+
+    /* Short description of why a reference could not be followed, None if it resolved or was not a reference */
+    pub fn problem(&self) -> Option<&'static str>
+    {
+        match self {
+            Resolution::Dangling(_) => Some("dangling reference"),
+            Resolution::NoValue(_) => Some("reference to a row without an amount"),
+            Resolution::Cycle(_) => Some("cyclic reference"),
+            Resolution::Direct | Resolution::Referenced(_) | Resolution::Missing => None,
+        }
+    }
+
+    // e.g. "cluster C0012 -> cluster C0009", used in messages and rejected_rows.csv
+    pub fn describe_path(&self) -> String
+    {
+        self.path()
+            .iter()
+            .map(|h| format!("{} {}", h.kind.name(), h.id))
+            .collect::<Vec<String>>()
+            .join(" -> ")
+    }
+}
+
+/* Why a project's delay (CompletionDelayDays or ScheduleSlippageDays, see DelayBasis) failed the plausibility check */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DelayIssue
+{
+    BelowMinimum { days: i64 },     // e.g. completion recorded before start
+    AboveMaximum { days: i64 },     // e.g. a typo year producing a multi-decade span
+}
+
+impl DelayIssue
+{
+    /* e.g. "ScheduleSlippageDays 4000 above maximum" for a delay of the given basis */
+    pub fn describe(&self, basis: DelayBasis) -> String
+    {
+        match self {
+            DelayIssue::BelowMinimum { days } => format!("{} {days} below minimum", basis.field()),
+            DelayIssue::AboveMaximum { days } => format!("{} {days} above maximum", basis.field()),
+        }
+    }
+}
+
+/* Why one of a project's dates may be misread (see try_parse_date); Inconsistent is the stronger of the two */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DateIssue
+{
+    Ambiguous,      // another supported format reads the text as a different date (e.g. 03/04/2022)
+    Inconsistent,   // only read with a format other than the column's dominant one
+}
+
+impl DateIssue
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            DateIssue::Ambiguous => "ambiguous",
+            DateIssue::Inconsistent => "inconsistent",
+        }
+    }
+}
+
+/* Why a project's coordinates failed the geographic check (see controller::geography) */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoordIssue
+{
+    Zero,                           // latitude or longitude is 0, a placeholder rather than a position; cleared
+    Swapped,                        // latitude and longitude exchanged; swapped back
+    OutsideCountry,                 // not within the Philippines; cleared
+    FarFromProvince { km: i64 },    // inside the country but far from the declared province; kept
+}
+
+impl CoordIssue
+{
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            CoordIssue::Zero => "zero",
+            CoordIssue::Swapped => "swapped",
+            CoordIssue::OutsideCountry => "outside the Philippines",
+            CoordIssue::FarFromProvince { .. } => "far from province",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project
 {
     // NOTE: All fields are optional to handle missing data
@@ -16,16 +162,28 @@ pub struct Project
     pub main_island: Option<String>,
     pub province: Option<String>,
     pub contractor: Option<String>,
+    pub contractor_entity: Option<String>,  // canonical name after entity resolution (see controller::entity)
+    pub contractor_members: Vec<String>,    // member entities if the contractor is a joint venture, empty otherwise
     pub type_of_work: Option<String>,
     pub approved_budget_for_contract: Option<f64>,
     pub contract_cost: Option<f64>,
     pub start_date: Option<NaiveDate>,
     pub actual_completion_date: Option<NaiveDate>,
+    pub target_completion_date: Option<NaiveDate>,
     pub lat: Option<f64>,
     pub lon: Option<f64>,
     pub cost_savings: Option<f64>,
     pub completion_delay_days: Option<i64>,
+    pub schedule_slippage_days: Option<i64>,    // actual minus target completion, negative if finished early
     pub contract_id: Option<String>, 
+    pub coord_source: CoordSource,
+    pub budget_resolution: Resolution,
+    pub cost_resolution: Resolution,
+    pub delay_issue: Option<DelayIssue>,    // set when the delay the reports use (delay_days) was outside the configured bounds
+    pub coord_issue: Option<CoordIssue>,    // set when lat/lon failed the geographic check
+    pub date_issue: Option<DateIssue>,      // set when a date was ambiguous or off its column's format (the stronger issue)
+    pub source_file: String,    // input file the row was read from
+    pub source_row: u64,        // 1-based data row within that file (header excluded)
 }
 
 impl Project
@@ -41,18 +199,67 @@ impl Project
             main_island: None,
             province: None,
             contractor: None,
+            contractor_entity: None,
+            contractor_members: Vec::new(),
             type_of_work: None,
             approved_budget_for_contract: None,
             contract_cost: None,
             start_date: None,
             actual_completion_date: None,
+            target_completion_date: None,
             lat: None,
             lon: None,
             cost_savings: None,
             completion_delay_days: None,
+            schedule_slippage_days: None,
             contract_id: None,  
+            coord_source: CoordSource::Missing,
+            budget_resolution: Resolution::Missing,
+            cost_resolution: Resolution::Missing,
+            delay_issue: None,
+            coord_issue: None,
+            date_issue: None,
+            source_file: String::new(),
+            source_row: 0,
         }
     }
+
+    /* Delay in days under the chosen semantics */
+    pub fn delay_days(&self, basis: DelayBasis) -> Option<i64>
+    {
+        match basis {
+            DelayBasis::Duration => self.completion_delay_days,
+            DelayBasis::Slippage => self.schedule_slippage_days,
+        }
+    }
+
+    /* Money columns without an amount for lack of data. A resolved reference without an amount is not missing:
+       ParentOnly allocation left that amount to the cluster/MYCA parent row. */
+    pub fn missing_amounts(&self) -> Vec<&'static str>
+    {
+        [
+            ("ApprovedBudgetForContract", self.approved_budget_for_contract, &self.budget_resolution),
+            ("ContractCost", self.contract_cost, &self.cost_resolution),
+        ]
+        .into_iter()
+        .filter(|(_, amount, resolution)| amount.is_none() && !matches!(resolution, Resolution::Referenced(_)))
+        .map(|(column, _, _)| column)
+        .collect()
+    }
+
+    /* True when the row has no CostSavings only because its amount is counted on the parent row (ParentOnly) */
+    pub fn amount_on_parent(&self) -> bool
+    {
+        self.cost_savings.is_none() && self.missing_amounts().is_empty()
+    }
+}
+
+impl Default for Project
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
 }
 
 // ---------- Parsing Helpers ----------
@@ -79,33 +286,192 @@ pub fn parse_int(s: &str) -> Option<i32>
     trimmed.parse::<i32>().ok()
 }
 
-/* Converts text into NaiveDate with support for multiple formats */
-pub fn try_parse_date(s: &str) -> Option<NaiveDate>
+// Supported date formats (in priority order, used to break ties when inferring a column's format)
+pub const DATE_FORMATS: [&str; 6] = [
+    "%d/%m/%Y",
+    "%Y-%m-%d",
+    "%m/%d/%Y",
+    "%d-%b-%y",
+    "%b %d, %Y",
+    "%B %d, %Y",
+];
+
+// Pseudo-format reported for columns of Excel serial dates (days since 1899-12-30), as read from workbooks
+pub const EXCEL_SERIAL: &str = "excel-serial";
+
+// Serials read as dates: 1950-01-01 to 9999-12-31 (smaller numbers are more likely years or day counts)
+const EXCEL_SERIAL_RANGE: std::ops::RangeInclusive<f64> = 18_264.0..=2_958_465.0;
+
+/* Outcome of parsing one date string */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedDate
 {
-    let s_trim = s.trim();
-    if s_trim.is_empty() {
-        return None;
+    pub date: Option<NaiveDate>,
+    pub ambiguous: bool,    // another format reads the same text as a different date (e.g. 03/04/2022)
+    pub inconsistent: bool, // only parsed with a format other than the column's dominant one
+}
+
+impl ParsedDate
+{
+    /* The value's issue, Inconsistent first when it is both */
+    pub fn issue(&self) -> Option<DateIssue>
+    {
+        if self.inconsistent {
+            Some(DateIssue::Inconsistent)
+        } else if self.ambiguous {
+            Some(DateIssue::Ambiguous)
+        } else {
+            None
+        }
+    }
+}
+
+/* How many values of a column each format parses, counted one value at a time */
+#[derive(Debug, Clone, Default)]
+pub struct FormatTally
+{
+    counts: [usize; DATE_FORMATS.len()],
+    serials: usize,
+}
+
+impl FormatTally
+{
+    pub fn add(&mut self, value: &str)
+    {
+        self.add_formats(DateReading::read(value).formats);
+    }
+
+    /* Counts one value by the candidate formats that read it (a DateReading's bit set) */
+    pub fn add_formats(&mut self, formats: u8)
+    {
+        if formats & 1 << EXCEL_SERIAL_BIT != 0 {
+            self.serials += 1;
+            return;
+        }
+        for i in 0..DATE_FORMATS.len() {
+            if formats & 1 << i != 0 {
+                self.counts[i] += 1;
+            }
+        }
+    }
+
+    /* The format that parsed the most values; ties go to the earlier entry of DATE_FORMATS */
+    pub fn dominant(&self) -> Option<&'static str>
+    {
+        let best = (0..DATE_FORMATS.len()).rev().max_by_key(|&i| self.counts[i])?;    // rev() so ties keep the earliest format
+        if self.serials > self.counts[best] {
+            return Some(EXCEL_SERIAL);
+        }
+        (self.counts[best] > 0).then_some(DATE_FORMATS[best])
     }
+}
+
+// Candidate formats of a date column: DATE_FORMATS in order, then EXCEL_SERIAL
+pub const DATE_CANDIDATES: usize = DATE_FORMATS.len() + 1;
+const EXCEL_SERIAL_BIT: usize = DATE_FORMATS.len();
+
+/* Position of a format among the date candidates, as used by DateReading::formats */
+pub fn candidate_index(format: &str) -> Option<usize>
+{
+    DATE_FORMATS.iter().position(|f| *f == format).or((format == EXCEL_SERIAL).then_some(EXCEL_SERIAL_BIT))
+}
 
-    // Supported date formats (in priority order)
-    let formats = [
-        "%d/%m/%Y",
-        "%Y-%m-%d",
-        "%m/%d/%Y",
-        "%d-%b-%y",
-        "%b %d, %Y",
-        "%B %d, %Y",
-    ];
+/* What one look at a date string tells before its column's format is known. Whether a value is ambiguous or
+   off-format only depends on which formats read it, so only an ambiguous value needs its text parsed again. */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateReading
+{
+    pub formats: u8,    // bit i set when candidate i (see candidate_index) reads the value
+    pub date: Option<NaiveDate>,    // first reading in priority order, final unless ambiguous
+    pub ambiguous: bool,
+}
 
-    for f in &formats {
-        if let Ok(date) = NaiveDate::parse_from_str(s_trim, f) {
-            return Some(date);
+impl DateReading
+{
+    pub fn read(s: &str) -> Self
+    {
+        let mut reading = DateReading::default();
+        let v = s.trim();
+        if v.is_empty() {
+            return reading;
         }
+        if let Some(date) = excel_serial_date(v) {
+            return DateReading { formats: 1 << EXCEL_SERIAL_BIT, date: Some(date), ambiguous: false };
+        }
+        for (i, f) in DATE_FORMATS.iter().enumerate() {
+            if let Ok(date) = NaiveDate::parse_from_str(v, f) {
+                reading.formats |= 1 << i;
+                match reading.date {
+                    Some(first) => reading.ambiguous |= first != date,
+                    None => reading.date = Some(date),
+                }
+            }
+        }
+        reading
+    }
+
+    /* True when the value was read but not by `dominant` */
+    pub fn off_format(&self, dominant: Option<&str>) -> bool
+    {
+        let index = dominant.and_then(candidate_index);
+        self.formats != 0 && index.is_some_and(|i| self.formats & 1 << i == 0)
     }
 
-    None
+    /* The reading judged against its column's dominant format, as try_parse_date would parse it
+       (except the date of an ambiguous value, which only its text can decide) */
+    pub fn parsed(&self, dominant: Option<&str>) -> ParsedDate
+    {
+        ParsedDate { date: self.date, ambiguous: self.ambiguous, inconsistent: self.off_format(dominant) }
+    }
 }
 
+/* Picks the format that parses the most values of a column (see FormatTally::dominant) */
+pub fn infer_date_format<'a>(values: impl Iterator<Item = &'a str>) -> Option<&'static str>
+{
+    let mut tally = FormatTally::default();
+    for v in values {
+        tally.add(v);
+    }
+    tally.dominant()
+}
+
+/* Date of an Excel serial number; the fraction (time of day) is ignored */
+pub fn excel_serial_date(s: &str) -> Option<NaiveDate>
+{
+    let serial = s.trim().parse::<f64>().ok().filter(|v| EXCEL_SERIAL_RANGE.contains(v))?;
+    NaiveDate::from_ymd_opt(1899, 12, 30)?.checked_add_days(chrono::Days::new(serial.trunc() as u64))
+}
+
+/* Converts text into NaiveDate with support for multiple formats and Excel serial numbers.
+   `dominant` is the format inferred from every value of the column in its file; it is tried first and decides ambiguous values. */
+pub fn try_parse_date(s: &str, dominant: Option<&str>) -> ParsedDate
+{
+    let mut result = ParsedDate { date: None, ambiguous: false, inconsistent: false };
+    let s_trim = s.trim();
+    if s_trim.is_empty() {
+        return result;
+    }
+
+    // No text format reads a plain number, so a serial is never ambiguous
+    if let Some(date) = excel_serial_date(s_trim) {
+        result.date = Some(date);
+        result.inconsistent = dominant.is_some_and(|d| d != EXCEL_SERIAL);
+        return result;
+    }
+
+    // Dominant format first, then the rest in priority order (EXCEL_SERIAL as a chrono format never matches text)
+    let ordered = dominant.into_iter().chain(DATE_FORMATS.iter().copied().filter(|f| Some(*f) != dominant));
+    let readings: Vec<(&str, NaiveDate)> = ordered
+        .filter_map(|f| NaiveDate::parse_from_str(s_trim, f).ok().map(|d| (f, d)))
+        .collect();
+
+    if let Some(&(format, date)) = readings.first() {
+        result.date = Some(date);
+        result.ambiguous = readings.iter().any(|&(_, other)| other != date);
+        result.inconsistent = dominant.is_some_and(|d| d != format);
+    }
+    result
+}
 
 // ---------- Utility math ----------
 /* Gets median */
@@ -151,4 +517,84 @@ pub fn truncate(s: &str, max_len: usize) -> String
     {
         s.to_string()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate>
+    {
+        NaiveDate::from_ymd_opt(y, m, d)
+    }
+
+    #[test]
+    fn the_dominant_format_decides_ambiguous_dates()
+    {
+        let day_first = try_parse_date("03/04/2022", Some("%d/%m/%Y"));
+        assert_eq!(day_first.date, date(2022, 4, 3));
+        assert!(day_first.ambiguous && !day_first.inconsistent);
+        assert_eq!(day_first.issue(), Some(DateIssue::Ambiguous));
+
+        let month_first = try_parse_date("03/04/2022", Some("%m/%d/%Y"));
+        assert_eq!(month_first.date, date(2022, 3, 4));
+        assert!(month_first.ambiguous);
+    }
+
+    #[test]
+    fn values_off_the_dominant_format_are_inconsistent()
+    {
+        let parsed = try_parse_date("25/04/2022", Some("%m/%d/%Y"));
+        assert_eq!(parsed.date, date(2022, 4, 25));
+        assert!(parsed.inconsistent && !parsed.ambiguous);
+        assert_eq!(parsed.issue(), Some(DateIssue::Inconsistent));
+
+        let serial = try_parse_date("44651", Some("%Y-%m-%d"));
+        assert_eq!(serial.date, date(2022, 3, 31));
+        assert!(serial.inconsistent);
+        assert!(!try_parse_date("44651", Some(EXCEL_SERIAL)).inconsistent);
+    }
+
+    #[test]
+    fn clean_and_unreadable_dates()
+    {
+        let clean = try_parse_date(" 2022-04-25 ", Some("%Y-%m-%d"));
+        assert_eq!(clean.date, date(2022, 4, 25));
+        assert_eq!(clean.issue(), None);
+        assert_eq!(try_parse_date("25/04/2022", None).issue(), None);   // no dominant format to be off
+        assert_eq!(try_parse_date("", None).date, None);
+        assert_eq!(try_parse_date("next week", None).date, None);
+        assert_eq!(try_parse_date("2021", None).date, None);     // a year, not a serial
+    }
+
+    #[test]
+    fn formats_are_inferred_from_every_value()
+    {
+        let values = ["03/04/2022", "05/06/2022", "04/25/2022"];
+        assert_eq!(infer_date_format(values.into_iter()), Some("%m/%d/%Y"));
+        assert_eq!(infer_date_format(values[..2].iter().copied()), Some("%d/%m/%Y"));    // a tie keeps the earlier format
+        assert_eq!(infer_date_format(["44651", "44652", "2022-01-01"].into_iter()), Some(EXCEL_SERIAL));
+        assert_eq!(infer_date_format(["", "n/a"].into_iter()), None);
+    }
+
+    #[test]
+    fn a_reading_judges_a_value_like_a_full_parse()
+    {
+        let values = ["03/04/2022", "25/04/2022", "04/25/2022", "05/05/2022", "2022-04-25", "44651", "", "next week"];
+        let dominants = [None, Some("%d/%m/%Y"), Some("%m/%d/%Y"), Some("%Y-%m-%d"), Some(EXCEL_SERIAL)];
+        for value in values {
+            let reading = DateReading::read(value);
+            for dominant in dominants {
+                let parsed = try_parse_date(value, dominant);
+                let judged = reading.parsed(dominant);
+                assert_eq!((judged.ambiguous, judged.inconsistent), (parsed.ambiguous, parsed.inconsistent), "{value} {dominant:?}");
+                if !reading.ambiguous {
+                    assert_eq!(judged.date, parsed.date, "{value} {dominant:?}");
+                }
+            }
+        }
+        assert!(DateReading::read("03/04/2022").ambiguous);
+        assert!(!DateReading::read("05/05/2022").ambiguous);   // both day-first and month-first give the same date
+    }
+}
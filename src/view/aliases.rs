@@ -0,0 +1,48 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::controller::entity::AliasEntry;
+use csv::Writer;
+use std::error::Error;
+use std::path::PathBuf;
+
+/* Writes contractor_aliases.csv: every raw contractor name, the entity it was merged into and why,
+   the member firms of joint ventures and the entity it may be a misspelling of (never merged automatically).
+   The file can be edited and passed back with --contractor-aliases (RawName, Canonical and,
+   if present, Members are read). Returns the path written (see aliases_path). */
+pub fn export_aliases(aliases: &[AliasEntry], config: &RunConfig) -> Result<PathBuf, Box<dyn Error>>
+{
+    let path = aliases_path(config);
+    let mut wtr = Writer::from_path(&path)?;
+    wtr.write_record(["RawName", "Canonical", "Method", "Projects", "Members", "FuzzySuggestion", "Similarity"])?;
+    for a in aliases {
+        wtr.write_record([
+            a.raw.as_str(),
+            a.canonical.as_str(),
+            a.method.name(),
+            &a.projects.to_string(),
+            &a.members.join("; "),    // joint venture members, empty for a single firm
+            a.suggestion.as_deref().unwrap_or(""),
+            &a.similarity.map(|s| format!("{s:.3}")).unwrap_or_default(),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(path)
+}
+
+/* contractor_aliases.csv in the output directory, unless that file is the reviewed table passed with
+   --contractor-aliases: the user's edits are never overwritten, the table goes to contractor_aliases_generated.csv */
+fn aliases_path(config: &RunConfig) -> PathBuf
+{
+    let path = config.output_path("contractor_aliases.csv");
+    let reviewed = config.contractor_aliases.as_ref().and_then(|p| p.canonicalize().ok());
+    if reviewed.is_some() && path.canonicalize().ok() == reviewed {
+        config.output_path("contractor_aliases_generated.csv")
+    } else {
+        path
+    }
+}
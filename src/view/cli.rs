@@ -0,0 +1,220 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::{AllocationPolicy, DelayBasis, DelayPolicy, JvAttribution, OutputFormat, RunConfig, YearKey};    // imports run configuration (input path, output directory, analysis window)
+use crate::controller; // imports controller
+use crate::controller::registry::ReportRegistry;    // reports that can be selected with --only
+use crate::view::{menu, quality};
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// Exit codes returned to the shell
+const EXIT_OK: u8 = 0;
+const EXIT_FAILURE: u8 = 1;    // loading or report generation failed
+const EXIT_USAGE: u8 = 2;      // bad command line
+
+const USAGE: &str = "\
+Usage: CSADPRG_MP2 [COMMAND] [OPTIONS]
+
+Commands:
+  menu                  Start the interactive menu (default when no command is given)
+  load                  Load and validate the dataset, then exit
+  report                Load the dataset and generate reports
+  summary               Load the dataset and generate summary.json only
+  help                  Show this message
+
+Options (all commands):
+  --input <PATH>        Dataset to load: CSV or .xlsx/.xlsm/.xlsb/.xls/.ods, or a glob such as 'data/*.csv'
+                        (default: dpwh_flood_control_projects.csv); repeat to merge several files into one dataset
+  --sheet <NAME|N>      Workbook sheet to read, by name or 1-based position (default: first sheet)
+  --header-row <N>      Row holding the column names; rows above it are skipped (default: 1)
+  --output-dir <DIR>    Directory for generated files, created if missing (default: .)
+  --years <FROM-TO>     Analysis window, inclusive (default: 2021-2023)
+  --year-key <COLUMN>   Column the window applies to: StartDate or FundingYear (default: StartDate)
+  --allocation <POLICY> How clustered/MYCA amounts are shared: inherit, parent-only, even, pro-rata
+                        (default: inherit)
+  --delay-basis <BASIS> Meaning of delay in the reports: duration (ActualCompletionDate - StartDate)
+                        or slippage (ActualCompletionDate - TargetCompletionDate) (default: duration)
+  --delay-bounds <MIN:MAX>
+                        Plausible range of the delay the reports use (default: 0:3650 for duration,
+                        -3650:3650 for slippage)
+  --delay-policy <POLICY>
+                        What to do with delays outside the bounds: keep, exclude, cap (default: exclude)
+  --contractor-aliases <CSV>
+                        Reviewed contractor alias table (an edited contractor_aliases.csv); its RawName -> Canonical
+                        pairs override the automatic name matching, and its optional Members column (member firms
+                        separated by ';', empty for a single firm) overrides joint-venture detection
+  --no-snapshot         Always re-read the dataset; do not reuse or write <input>.snapshot
+
+Options (report and menu):
+  --only <NAME>         Generate only the named report; may be repeated or comma-separated
+                        (see Reports below); in the menu it preselects [3] Choose Reports
+  --format <LIST>       Comma-separated renderings of report1-3: table, csv, json (default: table,csv)
+  --jv-attribution <MODE>
+                        How report2 credits joint ventures: entity (ranked as one contractor), full (every member
+                        gets the whole project) or share (members split its amounts equally) (default: entity)
+
+Exit codes: 0 = success, 1 = processing error, 2 = usage error
+";
+
+// USAGE followed by the reports of the registry
+fn usage(registry: &ReportRegistry) -> String
+{
+    let mut text = format!("{USAGE}\nReports:\n");
+    for report in registry.iter() {
+        text.push_str(&format!("  {:<20}  {}\n", report.name(), report.description()));
+    }
+    text
+}
+
+// Parsed form of the command line
+enum Command
+{
+    Menu(Vec<String>),  // reports preselected with --only; empty means every report
+    Load,
+    Report(Vec<String>),    // report names; empty means every report
+    Help,
+}
+
+/* Entry point for the binary: parses args (without program name) and runs the chosen mode */
+pub fn run(args: &[String]) -> ExitCode
+{
+    let registry = ReportRegistry::builtin();
+    let (command, config) = match parse_args(args, &registry) {
+        Ok(parsed) => parsed,
+        Err(msg) => {
+            eprintln!("error: {msg}\n\n{}", usage(&registry));
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    match execute(command, &config, &registry) {
+        Ok(()) => ExitCode::from(EXIT_OK),
+        Err(e) => {
+            eprintln!("\nerror: {e}");
+            ExitCode::from(EXIT_FAILURE)
+        }
+    }
+}
+
+fn parse_args(args: &[String], registry: &ReportRegistry) -> Result<(Command, RunConfig), String>
+{
+    let mut config = RunConfig::new();
+
+    // the command is the first argument unless it is an option; no command keeps the original interactive behaviour
+    let (command, rest) = match args.split_first() {
+        Some((first, rest)) if !first.starts_with('-') || matches!(first.as_str(), "-h" | "--help") => (first.as_str(), rest),
+        _ => ("menu", args),
+    };
+
+    let mut names: Vec<String> = Vec::new();
+    let mut inputs_given = false;
+    let generates_reports = matches!(command, "report" | "menu");    // report-only options are accepted by both
+    let mut iter = rest.iter();
+
+    while let Some(arg) = iter.next() {
+        // accepts both "--flag value" and "--flag=value"
+        let (flag, inline) = match arg.split_once('=') {
+            Some((f, v)) => (f, Some(v.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let mut value = || -> Result<String, String> {
+            match inline.clone() {
+                Some(v) => Ok(v),
+                None => iter.next().cloned().ok_or(format!("'{flag}' needs a value")),
+            }
+        };
+
+        match flag {
+            "--no-snapshot" if inline.is_none() => config.use_snapshot = false,
+            "--input" => {
+                if !inputs_given {
+                    config.inputs.clear();  // the first --input replaces the default dataset
+                    inputs_given = true;
+                }
+                config.inputs.push(PathBuf::from(value()?));
+            }
+            "--sheet" => config.sheet = Some(value()?),
+            "--header-row" => {
+                let raw = value()?;
+                config.header_row = raw.trim().parse().ok().filter(|&n: &usize| n >= 1).ok_or(format!("invalid header row '{raw}' (expected 1 or more)"))?;
+            }
+            "--contractor-aliases" => config.contractor_aliases = Some(PathBuf::from(value()?)),
+            "--output-dir" => config.output_dir = PathBuf::from(value()?),
+            "--years" => config.window.parse_years(&value()?)?,
+            "--allocation" => {
+                let name = value()?;
+                config.allocation = AllocationPolicy::from_name(&name).ok_or(format!("unknown allocation policy '{name}'"))?;
+            }
+            "--delay-basis" => {
+                let name = value()?;
+                config.delay_basis = DelayBasis::from_name(&name).ok_or(format!("unknown delay basis '{name}'"))?;
+            }
+            "--delay-bounds" => config.delay_bounds.parse_range(&value()?)?,
+            "--delay-policy" => {
+                let name = value()?;
+                config.delay_bounds.policy = DelayPolicy::from_name(&name).ok_or(format!("unknown delay policy '{name}'"))?;
+            }
+            "--year-key" => {
+                let name = value()?;
+                config.window.key = YearKey::from_name(&name).ok_or(format!("unknown year key '{name}'"))?;
+            }
+            "--format" if generates_reports => {
+                config.formats.clear();
+                for name in value()?.split(',').filter(|n| !n.trim().is_empty()) {
+                    let format = OutputFormat::from_name(name).ok_or_else(|| format!("unknown format '{}'", name.trim()))?;
+                    if !config.formats.contains(&format) {
+                        config.formats.push(format);
+                    }
+                }
+            }
+            "--jv-attribution" if generates_reports => {
+                let name = value()?;
+                config.jv_attribution = JvAttribution::from_name(&name).ok_or(format!("unknown joint venture attribution '{name}'"))?;
+            }
+            "--only" if generates_reports => {
+                for name in value()?.split(',').filter(|n| !n.trim().is_empty()) {
+                    let report = registry.get(name).ok_or_else(|| format!("unknown report '{}'", name.trim()))?;
+                    if !names.iter().any(|n| n == report.name()) {
+                        names.push(report.name().to_string());
+                    }
+                }
+            }
+            _ => return Err(format!("unexpected argument '{arg}' for '{command}'")),
+        }
+    }
+
+    let command = match command {
+        "menu" => Command::Menu(names),
+        "load" => Command::Load,
+        "summary" => Command::Report(vec!["summary".to_string()]),
+        "help" | "-h" | "--help" => Command::Help,
+        "report" => Command::Report(names),  // no filter means every report
+        other => return Err(format!("unknown command '{other}'")),
+    };
+    Ok((command, config))
+}
+
+fn execute(command: Command, config: &RunConfig, registry: &ReportRegistry) -> Result<(), Box<dyn Error>>
+{
+    match command {
+        Command::Menu(names) => menu::main_menu(config, registry, names),
+        Command::Load => {
+            let dataset = controller::load_file(config)?;
+            quality::print_quality(&dataset.quality, config);
+            Ok(())
+        }
+        Command::Report(names) => {
+            let dataset = controller::load_file(config)?;
+            controller::generate_reports(&dataset.projects, registry, &names, config)
+        }
+        Command::Help => {
+            print!("{}", usage(registry));
+            Ok(())
+        }
+    }
+}
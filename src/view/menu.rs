@@ -5,35 +5,78 @@ Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
 ********************/
 
 use std::io::{self, Write};
+use crate::config::RunConfig;   // imports run configuration
 use crate::controller; // imports controller
+use crate::controller::registry::ReportRegistry;    // reports listed by [3]
+use crate::view::quality;   // data-quality table printed after loading
 
-pub fn main_menu() -> Result<(), Box<dyn std::error::Error>> {
-    let mut loaded_projects: Option<Vec<crate::model::Project>> = None;
+pub fn main_menu(config: &RunConfig, registry: &ReportRegistry, selected: Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut loaded: Option<controller::Dataset> = None;
+    let mut selected = selected;    // report names from --only or chosen with [3]; empty means every report
     loop {
         println!("\nSelect Language Implementation:");
         println!("[1] Load the file");
         println!("[2] Generate Reports");
+        println!("[3] Choose Reports ({})", if selected.is_empty() { "all".to_string() } else { selected.join(", ") });
+        println!("[4] Exit");
         print!("\nEnter choice: ");
         io::stdout().flush()?;  // ensure prompt prints immediately
 
         let mut choice = String::new();
-        io::stdin().read_line(&mut choice)?;
+        if io::stdin().read_line(&mut choice)? == 0 {
+            return Ok(());  // stdin closed (EOF), nothing more to read
+        }
         let choice = choice.trim();
 
         match choice {
             "1" => {
-                loaded_projects = Some(controller::load_file()?);
+                let dataset = controller::load_file(config)?;
+                quality::print_quality(&dataset.quality, config);
+                loaded = Some(dataset);
             }
             "2" => {
-                if let Some(ref projects) = loaded_projects {
-                    controller::generate_reports(projects)?;    // generate reports from controller
+                if let Some(ref dataset) = loaded {
+                    controller::generate_reports(&dataset.projects, registry, &selected, config)?;    // generate reports from controller
                 } else {
                     println!("\nNo data loaded yet. Please choose [1] first.");
                 }
             }
+            "3" => {
+                selected = choose_reports(registry)?;
+            }
+            "4" => {
+                return Ok(());
+            }
             _ => {
-                println!("\nInvalid choice. Please enter 1 or 2.");
+                println!("\nInvalid choice. Please enter 1, 2, 3 or 4.");
             }
         }
     }
 }
+
+// Lists the registry and reads a comma-separated list of numbers or names; empty input selects every report
+fn choose_reports(registry: &ReportRegistry) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    println!();
+    let names = registry.names();
+    for (i, report) in registry.iter().enumerate() {
+        println!("[{}] {:<10} {}", i + 1, report.name(), report.description());
+    }
+    print!("\nReports to generate (e.g. 1,3 or report2; Enter for all): ");
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    let mut selected: Vec<String> = Vec::new();
+    for item in line.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let name = match item.parse::<usize>() {
+            Ok(n) => names.get(n.wrapping_sub(1)).map(|s| s.to_string()),
+            Err(_) => registry.get(item).map(|r| r.name().to_string()),
+        };
+        match name {
+            Some(n) if !selected.contains(&n) => selected.push(n),
+            Some(_) => {}
+            None => println!("Unknown report '{item}', skipped."),
+        }
+    }
+    Ok(selected)
+}
\ No newline at end of file
@@ -1,4 +1,9 @@
+pub mod aliases;
+pub mod cli;
 pub mod menu;
+pub mod quality;
+pub mod rejected;
+pub mod render;
 pub mod report1;
 pub mod report2;
 pub mod report3;
@@ -0,0 +1,188 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::controller::validation::{DateColumnSummary, MAX_SAMPLES, Sample, ValidationReport};
+use crate::model::CoordIssue;
+use csv::{Writer, WriterBuilder};
+use std::error::Error;
+use std::fs::File;
+use std::io::Write;
+
+// Count columns of data_quality.csv: every check has a column of its own, 0 on rows it does not apply to
+const COUNT_COLUMNS: [&str; 10] = ["Empty", "Unparseable", "Unresolved", "Failures", "Implausible", "Ambiguous", "OffFormat", "Unknown", "Conflicting", "Flagged"];
+
+// Coordinate issues in the order data_quality.csv lists them
+const COORD_ISSUES: [CoordIssue; 4] = [CoordIssue::Zero, CoordIssue::Swapped, CoordIssue::OutsideCountry, CoordIssue::FarFromProvince { km: 0 }];
+
+// Joins samples as "row 12: abc; row 40: x", naming the file as well when several were loaded
+fn format_samples(report: &ValidationReport, samples: &[Sample]) -> String
+{
+    samples
+        .iter()
+        .map(|s| if report.files.len() > 1 { format!("{} row {}: {}", s.file, s.row, s.value) } else { format!("row {}: {}", s.row, s.value) })
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+// Joins at most MAX_SAMPLES items, noting how many were left out
+fn join_limited<'a>(items: impl ExactSizeIterator<Item = &'a String>) -> String
+{
+    let total = items.len();
+    let shown: Vec<&str> = items.take(MAX_SAMPLES).map(|s| s.as_str()).collect();
+    if total > shown.len() {
+        format!("{}, ... ({} more)", shown.join(", "), total - shown.len())
+    } else {
+        shown.join(", ")
+    }
+}
+
+// Flagged points with the given issue, in row order
+fn coord_points(report: &ValidationReport, issue: &CoordIssue) -> Vec<Sample>
+{
+    report.coordinates.flagged.iter()
+        .filter(|f| f.issue.name() == issue.name())
+        .map(|f| f.sample.clone())
+        .collect()
+}
+
+// "StartDate (%d/%m/%Y)", with the file when several were loaded
+fn date_field(report: &ValidationReport, d: &DateColumnSummary) -> String
+{
+    let format = d.dominant_format.as_deref().unwrap_or("unknown");
+    if report.files.len() > 1 {
+        format!("{} in {} ({format})", d.column, d.file)
+    } else {
+        format!("{} ({format})", d.column)
+    }
+}
+
+/* Prints the data-quality table after a load */
+pub fn print_quality(report: &ValidationReport, config: &RunConfig)
+{
+    println!("\nData Quality ({} rows checked, {} issues)\n", report.total_rows, report.issue_count());
+
+    println!(
+        "| {:<26} | {:>8} | {:>11} | {:>10} | {:>8} | {:>11} |",
+        "Column / Field", "Empty", "Unparseable", "Unresolved", "Failures", "Implausible"
+    );
+    println!("|{:-<28}|{:-<10}|{:-<13}|{:-<12}|{:-<10}|{:-<13}|", "", "", "", "", "", "");
+
+    for c in &report.columns {
+        println!(
+            "| {:<26} | {:>8} | {:>11} | {:>10} | {:>8} | {:>11} |",
+            c.column, c.empty, c.unparseable, c.unresolved, "-", "-"
+        );
+    }
+    for d in &report.derived {
+        println!(
+            "| {:<26} | {:>8} | {:>11} | {:>10} | {:>8} | {:>11} |",
+            d.field, "-", "-", "-", d.failures, d.implausible
+        );
+    }
+
+    // checks that do not fit the table's columns, labelled like their data_quality.csv columns
+    println!(
+        "\nProvince/Region/MainIsland: {} unknown, {} conflicting",
+        report.locations.unknown, report.locations.inconsistent
+    );
+    let by_issue: Vec<String> = COORD_ISSUES.iter()
+        .map(|issue| format!("{} {}", coord_points(report, issue).len(), issue.name()))
+        .collect();
+    println!("Coordinates: {} flagged ({})", report.coordinates.total(), by_issue.join(", "));
+
+    for d in &report.dates {
+        if !d.samples.is_empty() {
+            let file = if report.files.len() > 1 { format!(" in {}", d.file) } else { String::new() };
+            println!(
+                "\n{}{file} ambiguous or off-format dates ({} ambiguous, {} off-format): {}",
+                d.column, d.ambiguous, d.inconsistent, format_samples(report, &d.samples)
+            );
+        }
+    }
+
+    let refs = &report.references;
+    if !refs.dangling_ids.is_empty() {
+        println!("\nDangling references ({}): {}", refs.dangling_ids.len(), join_limited(refs.dangling_ids.iter()));
+    }
+    if !refs.cycle_paths.is_empty() {
+        println!("Cyclic references ({}): {}", refs.cycle_paths.len(), join_limited(refs.cycle_paths.iter()));
+    }
+
+    let places = &report.locations;
+    if !places.unknown_values.is_empty() {
+        println!("\nLocations not in the reference table ({}): {}", places.unknown_values.len(), join_limited(places.unknown_values.iter()));
+    }
+    if !places.samples.is_empty() {
+        println!("Inconsistent Province/Region/MainIsland ({} rows): {}", places.inconsistent, format_samples(report, &places.samples));
+    }
+
+    let points = &report.coordinates;
+    if !points.flagged.is_empty() {
+        let shown: Vec<Sample> = points.flagged.iter().take(MAX_SAMPLES).map(|f| f.sample.clone()).collect();
+        println!("Flagged coordinates ({} rows, all listed in data_quality.json): {}", points.total(), format_samples(report, &shown));
+    }
+
+    println!(
+        "(Details exported to {} and {})",
+        config.output_path("data_quality.json").display(),
+        config.output_path("data_quality.csv").display()
+    );
+}
+
+/* Exports the data-quality findings as data_quality.json and data_quality.csv */
+pub fn export_quality(report: &ValidationReport, config: &RunConfig) -> Result<(), Box<dyn Error>>
+{
+    // ----- Save JSON -----
+    let json_path = config.output_path("data_quality.json");
+    let mut file = File::create(&json_path)?;
+    file.write_all(serde_json::to_string_pretty(report)?.as_bytes())?;
+
+    // ----- Save CSV -----
+    let csv_path = config.output_path("data_quality.csv");
+    let mut wtr = WriterBuilder::new().from_path(&csv_path)?;
+    let mut header = vec!["Kind", "Field"];
+    header.extend(COUNT_COLUMNS);
+    header.push("Samples");
+    wtr.write_record(&header)?;
+
+    for c in &report.columns {
+        let counts = [("Empty", c.empty), ("Unparseable", c.unparseable), ("Unresolved", c.unresolved)];
+        write_counts(&mut wtr, "column", &c.column, &counts, &format_samples(report, &c.samples))?;
+    }
+    for d in &report.dates {
+        let counts = [("Ambiguous", d.ambiguous), ("OffFormat", d.inconsistent)];
+        write_counts(&mut wtr, "date_format", &date_field(report, d), &counts, &format_samples(report, &d.samples))?;
+    }
+    // values the reference table does not know, and rows whose province, region and island contradict each other
+    let places = &report.locations;
+    let counts = [("Unknown", places.unknown), ("Conflicting", places.inconsistent)];
+    write_counts(&mut wtr, "location", "Province/Region/MainIsland", &counts, &format_samples(report, &places.samples))?;
+    // one row per issue, listing every flagged point like the JSON does
+    for issue in COORD_ISSUES {
+        let points = coord_points(report, &issue);
+        let field = format!("ProjectLatitude/ProjectLongitude ({})", issue.name());
+        write_counts(&mut wtr, "coordinates", &field, &[("Flagged", points.len())], &format_samples(report, &points))?;
+    }
+    for d in &report.derived {
+        let counts = [("Failures", d.failures), ("Implausible", d.implausible)];
+        write_counts(&mut wtr, "derived", &d.field, &counts, &format_samples(report, &d.samples))?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+// One row of data_quality.csv; count columns missing from `counts` are 0
+fn write_counts(wtr: &mut Writer<File>, kind: &str, field: &str, counts: &[(&str, usize)], samples: &str) -> Result<(), Box<dyn Error>>
+{
+    let mut record = vec![kind.to_string(), field.to_string()];
+    record.extend(COUNT_COLUMNS.iter().map(|column| {
+        counts.iter().find(|(name, _)| name == column).map_or(0, |(_, n)| *n).to_string()
+    }));
+    record.push(samples.to_string());
+    wtr.write_record(&record)?;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::RunConfig;
+use crate::controller::loader;
+use crate::controller::validation::RejectedRow;
+use csv::Writer;
+use std::error::Error;
+use std::path::PathBuf;
+
+/* Writes rejected_rows.csv: the original columns (read back from `files`) followed by SourceFile, SourceRow, Scope and Reason */
+pub fn export_rejected(headers: &[String], rows: &[RejectedRow], files: &[PathBuf], config: &RunConfig) -> Result<(), Box<dyn Error>>
+{
+    let mut wtr = Writer::from_path(config.output_path("rejected_rows.csv"))?;   // every row has the header's width
+
+    let mut header_row = headers.to_vec();
+    header_row.extend(["SourceFile", "SourceRow", "Scope", "Reason"].map(String::from));
+    wtr.write_record(&header_row)?;
+
+    loader::read_rejected(files, headers, rows, config, |r, mut record| {
+        // truncate long rows and pad short ones so the extra columns stay aligned under their headers
+        record.resize(headers.len(), String::new());
+        record.push(r.source.clone());
+        record.push(r.row.to_string());
+        record.push(r.scope.name().to_string());
+        record.push(r.reasons.join("; "));
+        wtr.write_record(&record)?;
+        Ok(())
+    })?;
+
+    wtr.flush()?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+/********************
+Last names: Abdulrahman, Bilanes, Cruz, Nicolas
+Language: JavaScript
+Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
+********************/
+
+use crate::config::{OutputFormat, RunConfig};
+use crate::controller::tabular::{ReportResult, TableRow};
+use csv::WriterBuilder;
+use serde::Serialize;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/* Writes the rows as CSV: TableRow::COLUMNS as the header, one record per row */
+pub fn write_csv<R: TableRow>(path: &Path, rows: &[R]) -> Result<(), Box<dyn Error>>
+{
+    let mut wtr = WriterBuilder::new().from_path(path)?;
+    wtr.write_record(R::COLUMNS)?;
+    for r in rows {
+        wtr.write_record(r.record())?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/* Writes the whole result (title, settings, rows) as pretty JSON */
+pub fn write_json<R: Serialize>(path: &Path, result: &ReportResult<R>) -> Result<(), Box<dyn Error>>
+{
+    let mut file = File::create(path)?;
+    file.write_all(serde_json::to_string_pretty(result)?.as_bytes())?;
+    Ok(())
+}
+
+/* Writes <stem>.csv and/or <stem>.json as selected in the config; returns the files written */
+pub fn export_files<R: TableRow + Serialize>(result: &ReportResult<R>, stem: &str, config: &RunConfig) -> Result<Vec<PathBuf>, Box<dyn Error>>
+{
+    let mut written = Vec::new();
+    if config.formats.contains(&OutputFormat::Csv) {
+        let path = config.output_path(&format!("{stem}.csv"));
+        write_csv(&path, &result.rows)?;
+        written.push(path);
+    }
+    if config.formats.contains(&OutputFormat::Json) {
+        let path = config.output_path(&format!("{stem}.json"));
+        write_json(&path, result)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/* Aligned terminal table: text columns left-aligned, numeric columns right-aligned */
+pub fn plain_table(columns: &[&str], records: &[Vec<String>]) -> Result<String, fmt::Error>
+{
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| records.iter().filter_map(|r| r.get(i)).map(|v| v.chars().count()).max().unwrap_or(0).max(c.len()))
+        .collect();
+    let numeric: Vec<bool> = (0..columns.len())
+        .map(|i| !records.is_empty() && records.iter().all(|r| r.get(i).is_some_and(|v| v.parse::<f64>().is_ok())))
+        .collect();
+
+    let mut out = String::new();
+    let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    writeln!(out, "{}", line(columns.iter().zip(&widths).map(|(c, w)| format!("{c:<w$}")).collect()))?;
+    writeln!(out, "|{}|", widths.iter().map(|w| "-".repeat(w + 2)).collect::<Vec<String>>().join("|"))?;
+    for r in records {
+        let cells = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let v = r.get(i).map(String::as_str).unwrap_or("");
+                if numeric[i] { format!("{v:>w$}") } else { format!("{v:<w$}") }
+            })
+            .collect();
+        writeln!(out, "{}", line(cells))?;
+    }
+    Ok(out)
+}
+
+// "a.csv and b.json", for the "(Full table exported to ...)" line
+pub fn describe_paths(paths: &[PathBuf]) -> String
+{
+    paths.iter().map(|p| p.display().to_string()).collect::<Vec<String>>().join(" and ")
+}
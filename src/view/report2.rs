@@ -4,99 +4,67 @@ Language: JavaScript
 Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
 ********************/
 
-use crate::model::{Project, truncate, round2};   // imports Project struct and utility functions from model
-use csv::WriterBuilder; // enables CSV writing
-use serde::Serialize;   // enables serialization for CSVs
-use std::cmp::Ordering; // enables Ordering for sorting
-use std::collections::HashMap;  // enables HashMap usage
-use std::error::Error;  // allows Result<(), Box<dyn Error>> (error handling)
+use crate::config::RunConfig;   // imports run configuration
+use crate::controller::registry::Report;    // lets the registry list and run this report
+use crate::controller::report2::{self, Row}; // computes the rows
+use crate::controller::tabular::ReportResult;
+use crate::model::{Project, truncate};   // imports Project struct and utility functions from model
+use std::fmt::{self, Write};    // console output is buffered so reports can run concurrently
 use num_format::{ToFormattedString};    // for formatting numbers with commas
 
-#[derive(Serialize)]
-struct Row
-{
-    contractor: String,
-    num_projects: usize,
-    reliability_index: f64,
-    risk_flag: String,
-    total_cost: f64,
-    avg_delay: f64,
-    total_savings: f64,
-}
+/* REQ-0007: top contractors by total cost */
+pub struct TopContractors;
 
-pub fn report_top_contractors(projects: &[Project]) -> Result<(), Box<dyn Error>>
+impl Report for TopContractors
 {
-    println!("\nReport 2: Top Contractors Performance Ranking\n");
-    println!("Top Contractors Performance Ranking (Top 15 by TotalCost, >=5 Projects)\n");
+    type Row = Row;
 
-    // Group by Contractor
-    let mut by_contractor: HashMap<String, Vec<&Project>> = HashMap::new();
-    for p in projects.iter()
+    fn name(&self) -> &'static str
     {
-        let key = p.contractor.clone().unwrap_or_else(|| "Unknown".to_string());    // get contractor name or "Unknown" if missing as a key
-        by_contractor.entry(key).or_insert_with(Vec::new).push(p);  // adds project to group (contractor)
+        "report2"
     }
 
-    let mut rows: Vec<Row> = Vec::new(); // stores rows for report. Each row will hold metrics for one contractor (only contractors with >=5 projects)
-
-    for (contractor, group) in by_contractor
+    fn description(&self) -> &'static str
     {
-        if group.len() < 5 { continue; }    // skip contractors with less than 5 projects
-
-        let avg_delay = group
-            .iter()
-            .filter_map(|p| p.completion_delay_days)    // gets completion_delay_days if exists
-            .map(|d| d as f64)  // converts i64 to f64
-            .sum::<f64>()
-            / (group.len() as f64);
-            // BASICALLY: avg_delay = total_delay_days / num_projects
-
-        let total_savings: f64 = group.iter().filter_map(|p| p.cost_savings).sum(); // sums up cost_savings for all projects in group
-        let total_cost: f64 = group.iter().filter_map(|p| p.contract_cost).sum();   // sums up contract_cost for all projects in group
+        "Top Contractors Performance Ranking"
+    }
 
-        let mut reliability = (1.0 - (avg_delay / 90.0)) * (total_savings / total_cost) * 100.0;
-        if reliability > 100.0 { 
-            reliability = 100.0; 
-        } 
-        if reliability < 0.0 { 
-            reliability = 0.0; 
-        }
-        // reliability index = (1 - (avg delay / 90)) * (total savings / total cost) * 100 (capped at 100)
+    fn file_stem(&self) -> &'static str
+    {
+        "report2_top_contractors"
+    }
 
-        let risk_flag = if reliability < 50.0 { 
-            "High Risk" 
-        } else { 
-            "OK" 
-        };
+    fn compute(&self, projects: &[Project], config: &RunConfig) -> ReportResult<Row>
+    {
+        report2::top_contractors(projects, config)
+    }
 
-        rows.push(Row
-        {
-            contractor,
-            num_projects: group.len(),
-            reliability_index: round2(reliability),
-            risk_flag: risk_flag.to_string(),
-            total_cost: round2(total_cost),
-            avg_delay: round2(avg_delay),
-            total_savings: round2(total_savings),
-        });
+    fn heading(&self, result: &ReportResult<Row>) -> String
+    {
+        format!("\nReport 2: {}\n{}\n\n{} (Top 15 by TotalCost, >=5 Projects)\n\n", result.title, result.settings, result.title)
     }
 
-    // sort & truncate
-    // Sort descending by total_cost
-    rows.sort_by(|a, b| b.total_cost.partial_cmp(&a.total_cost).unwrap_or(Ordering::Equal));
-    rows.truncate(15);  // keep only top 15 contractors by total_cost
+    fn render_table(&self, result: &ReportResult<Row>) -> Result<String, fmt::Error>
+    {
+        render_table(result)
+    }
+}
 
+/* Terminal table with a Rank column; amounts with thousands separators */
+pub fn render_table(result: &ReportResult<Row>) -> Result<String, fmt::Error>
+{
+    let mut out = String::new();
     // ---------- Print Table ----------
-    println!(
+    writeln!(out,
         "| {:<4} | {:<45} | {:>16} | {:>12} | {:>8} | {:>14} | {:>16} | {:<9} |",
         "Rank", "Contractor", "TotalCost", "NumProjects", "AvgDelay", "TotalSavings", "ReliabilityIndex", "RiskFlag"
-    );
-    println!(
+    )?;
+    writeln!(out,
         "|{:-<6}|{:-<47}|{:-<18}|{:-<14}|{:-<10}|{:-<16}|{:-<18}|{:-<11}|",
         "", "", "", "", "", "", "", ""
-    );
+    )?;
 
-    for (i, r) in rows.iter().enumerate()
+    for (i, r) in result.rows.iter().enumerate()
     {
         let formatted_total_cost = format!(
             "{}.{:02}",
@@ -109,7 +77,7 @@ pub fn report_top_contractors(projects: &[Project]) -> Result<(), Box<dyn Error>
             (r.total_savings as u64).to_formatted_string(&num_format::Locale::en),
             (r.total_savings.fract() * 100.0).round() as u64
         );
-        println!(
+        writeln!(out,
             "| {:<4} | {:<45} | {:>14} | {:>12} | {:>8.1} | {:>14} | {:>16.2} | {:<9} |",
             i + 1,
             truncate(&r.contractor, 45),
@@ -119,35 +87,8 @@ pub fn report_top_contractors(projects: &[Project]) -> Result<(), Box<dyn Error>
             formatted_total_savings,
             r.reliability_index,
             r.risk_flag
-        );
+        )?;
     }
 
-    // ----- Save CSV -----
-    let mut wtr = WriterBuilder::new().from_path("report2_top_contractors.csv")?;
-    wtr.write_record(&[
-        "Contractor",
-        "NumProjects",
-        "TotalCost",
-        "AvgDelay",
-        "TotalSavings",
-        "ReliabilityIndex",
-        "RiskFlag",
-    ])?;
-    
-    println!("(Full table exported to report2_top_contractors.csv)\n");
-
-    for r in rows
-    {
-        wtr.write_record(&[
-            &r.contractor,
-            &r.num_projects.to_string(),
-            &format!("{:.2}", r.total_cost),
-            &format!("{:.2}", r.avg_delay),
-            &format!("{:.2}", r.total_savings),
-            &format!("{:.2}", r.reliability_index),
-            &r.risk_flag,
-        ])?;
-    }
-    wtr.flush()?;
-    Ok(())
+    Ok(out)
 }
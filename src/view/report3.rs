@@ -4,111 +4,70 @@ Language: JavaScript
 Paradigm(s): Procedural, Object-Oriented, Functional, Data-Driven, Immutable
 ********************/
 
-use crate::model::{Project, truncate, round2};
-use csv::WriterBuilder;
-use serde::Serialize;
-use std::collections::HashMap;
-use std::error::Error;
+use crate::config::RunConfig;
+use crate::controller::registry::Report;    // lets the registry list and run this report
+use crate::controller::report3::{self, Row}; // computes the rows
+use crate::controller::tabular::ReportResult;
+use crate::model::{Project, truncate};
+use std::fmt::{self, Write};    // console output is buffered so reports can run concurrently
 use num_format::{ToFormattedString};    // for formatting numbers with commas
 
-#[derive(Serialize)]
-struct Row {
-    year: i32,
-    type_of_work: String,
-    total_projects: usize,
-    avg_savings: f64,
-    overrun_rate: f64,
-    yoy_change: f64,
-}
-
-pub fn report_annual_trends(projects: &[Project]) -> Result<(), Box<dyn Error>> {
-    println!("\nReport 3: Annual Project Type Cost Overrun Trends\n");
+/* REQ-0008: savings and overruns per year and type of work */
+pub struct AnnualTrends;
 
-    // ----------- Group by (FundingYear, TypeOfWork, Vec<Project>) -----------
-    let mut map: HashMap<i32, HashMap<String, Vec<&Project>>> = HashMap::new();
+impl Report for AnnualTrends
+{
+    type Row = Row;
 
-    for p in projects.iter() {
-        if let (Some(year), Some(work)) = (p.funding_year, p.type_of_work.clone()) {
-            map.entry(year)
-                .or_default()
-                .entry(work)
-                .or_default()
-                .push(p);
-        }
+    fn name(&self) -> &'static str
+    {
+        "report3"
     }
 
-    // ----------- Compute Rows (without YoY) -----------
-    let mut rows: Vec<Row> = Vec::new();
-    let mut avg_savings_map: HashMap<(i32, String), f64> = HashMap::new();
-
-    for (year, type_map) in &map {
-        for (work, group) in type_map {
-            // Average savings for the group
-            let avg_savings = group
-                .iter()
-                .filter_map(|p| p.cost_savings)
-                .sum::<f64>()
-                / (group.len() as f64);
-
-            // Overrun rate = (# of projects where CostSavings < 0) / total projects * 100
-            let overruns = group
-                .iter()
-                .filter(|p| p.cost_savings.unwrap_or(0.0) < 0.0)
-                .count();
-            let overrun_rate = (overruns as f64 / group.len() as f64) * 100.0;
-
-            // Store avg_savings for YoY calculation later
-            avg_savings_map.insert((*year, work.clone()), avg_savings);
+    fn description(&self) -> &'static str
+    {
+        "Annual Project Type Cost Overrun Trends"
+    }
 
-            rows.push(Row {
-                year: *year,
-                type_of_work: work.clone(),
-                total_projects: group.len(),
-                avg_savings: round2(avg_savings),
-                overrun_rate: round2(overrun_rate),
-                yoy_change: 0.0, // fill later
-            });
-        }
+    fn file_stem(&self) -> &'static str
+    {
+        "report3_annual_trends"
     }
 
-    // ----------- Compute YoY (% change from previous year) -----------
-    // YoY = ((current year avg - previous year avg) / previous year avg) * 100 if previous year avg != 0
-    for row in rows.iter_mut() {
-        let curr = row.avg_savings;
+    fn compute(&self, projects: &[Project], config: &RunConfig) -> ReportResult<Row>
+    {
+        report3::annual_trends(projects, config)
+    }
 
-        if let Some(prev) = avg_savings_map.get(&(row.year - 1, row.type_of_work.clone())) {
-            if *prev != 0.0 {
-                row.yoy_change = round2(((curr - prev) / prev) * 100.0);
-            } else {
-                row.yoy_change = 0.0;
-            }
-        } else {
-            row.yoy_change = 0.0; // baseline year (2021)
-        }
+    fn heading(&self, result: &ReportResult<Row>) -> String
+    {
+        format!("\nReport 3: {}\n{}\n\n", result.title, result.settings)
     }
 
-    // Sort results by year ascending then avg_savings descending
-    rows.sort_by(|a, b| {
-        a.year
-            .cmp(&b.year)
-            .then(b.avg_savings.partial_cmp(&a.avg_savings).unwrap_or(std::cmp::Ordering::Equal))
-    });
+    fn render_table(&self, result: &ReportResult<Row>) -> Result<String, fmt::Error>
+    {
+        render_table(result)
+    }
+}
 
+/* Terminal table, grouped by FundingYear and TypeOfWork */
+pub fn render_table(result: &ReportResult<Row>) -> Result<String, fmt::Error> {
+    let mut out = String::new();
     // ----------- Print Table -----------
-    println!("Annual Project Type Cost Overrun Trends (Grouped by FundingYear and TypeOfWork)\n");
+    writeln!(out, "{} (Grouped by FundingYear and TypeOfWork)\n", result.title)?;
 
     // Header
-    println!(
+    writeln!(out,
         "| {:<6} | {:<45} | {:>14} | {:>14} | {:>14} | {:>14} |",
         "Year", "TypeOfWork", "TotalProjects", "AvgSavings", "OverrunRate", "YoYChange"
-    );
-    println!(
+    )?;
+    writeln!(out,
         "|{:-<8}|{:-<47}|{:-<16}|{:-<16}|{:-<16}|{:-<16}|",
         "", "", "", "", "", ""
-    );
+    )?;
 
     // Rows
-    for r in &rows {
+    for r in &result.rows {
         let type_of_work = truncate(&r.type_of_work, 45);
 
         let formatted_avg_savings = format!(
@@ -117,7 +76,7 @@ pub fn report_annual_trends(projects: &[Project]) -> Result<(), Box<dyn Error>>
             (r.avg_savings.fract() * 100.0).round() as u64
         );
 
-        println!(
+        writeln!(out,
             "| {:<6} | {:<45} | {:>14} | {:>14} | {:>14.2} | {:>14.2} |",
             r.year,
             type_of_work,
@@ -125,34 +84,9 @@ pub fn report_annual_trends(projects: &[Project]) -> Result<(), Box<dyn Error>>
             formatted_avg_savings,
             r.overrun_rate,
             r.yoy_change
-        );
-    }
-
-    println!("\n(Full table exported to report3_annual_trends.csv)\n");
-
-    // ----- Save CSV -----
-    let mut wtr = WriterBuilder::new().from_path("report3_annual_trends.csv")?;
-
-    wtr.write_record(&[
-        "FundingYear",
-        "TypeOfWork",
-        "TotalProjects",
-        "AvgSavings",
-        "OverrunRate",
-        "YoYChange",
-    ])?;
-    
-    for r in rows {
-        wtr.write_record(&[
-            &r.year.to_string(),
-            &r.type_of_work,
-            &r.total_projects.to_string(),
-            &format!("{:.2}", r.avg_savings),
-            &format!("{:.2}", r.overrun_rate),
-            &format!("{:.2}", r.yoy_change),
-        ])?;
+        )?;
     }
+    writeln!(out)?;    // blank line before the export note
 
-    wtr.flush()?;
-    Ok(())
+    Ok(out)
 }